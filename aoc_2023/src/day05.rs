@@ -1,7 +1,5 @@
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, interval::IntervalSet, solution::Solution};
 use itertools::Itertools;
-use rayon::iter::ParallelIterator;
-use rayon::slice::ParallelSlice;
 
 pub struct Day5;
 
@@ -9,7 +7,7 @@ impl Solution for Day5 {
     fn part_a(&self, input: &[String]) -> Answer {
         let parsed = parse(input);
         let seeds = parsed.seeds;
-        let mut min = usize::MAX;
+        let mut min = i64::MAX;
         for mut seed in seeds {
             for map in &parsed.maps {
                 seed = map.map(seed);
@@ -21,27 +19,20 @@ impl Solution for Day5 {
 
     fn part_b(&self, input: &[String]) -> Answer {
         let parsed = parse(input);
-        let seeds = parsed.seeds;
-        seeds
-            .par_chunks_exact(2)
-            .map(|sr| {
-                let mut min = usize::MAX;
-                for mut seed in sr[0]..=(sr[0] + sr[1]) {
-                    for map in &parsed.maps {
-                        seed = map.map(seed);
-                    }
-                    min = min.min(seed);
-                }
-                min
-            })
-            .min()
-            .unwrap()
-            .into()
+        let mut seeds = parsed
+            .seeds
+            .chunks_exact(2)
+            .map(|sr| sr[0]..sr[0] + sr[1])
+            .collect::<IntervalSet<_>>();
+        for map in &parsed.maps {
+            seeds = map.map_set(&seeds);
+        }
+        seeds.min().unwrap().into()
     }
 }
 
 struct Parsed {
-    seeds: Vec<usize>,
+    seeds: Vec<i64>,
     maps: Vec<Map>,
 }
 
@@ -50,17 +41,17 @@ struct Map {
 }
 
 struct Range {
-    src: usize,
-    dest: usize,
-    len: usize,
+    src: i64,
+    dest: i64,
+    len: i64,
 }
 
 fn parse(input: &[String]) -> Parsed {
     let mut lines = input.iter();
     let (_, seeds) = lines.next().unwrap().split_once("seeds: ").unwrap();
-    let seeds: Vec<usize> = seeds
+    let seeds: Vec<i64> = seeds
         .split_whitespace()
-        .map(|s| s.parse::<usize>().unwrap())
+        .map(|s| s.parse::<i64>().unwrap())
         .collect();
     let degrouped = lines
         .chunk_by(|line| !line.is_empty())
@@ -72,9 +63,9 @@ fn parse(input: &[String]) -> Parsed {
     for m in degrouped {
         let mut ranges = vec![];
         for entry in m {
-            let entry: Vec<usize> = entry
+            let entry: Vec<i64> = entry
                 .split_whitespace()
-                .map(|s| s.parse::<usize>().unwrap())
+                .map(|s| s.parse::<i64>().unwrap())
                 .collect();
             let (dest, src, len) = (entry[0], entry[1], entry[2]);
             ranges.push(Range { src, dest, len })
@@ -85,7 +76,7 @@ fn parse(input: &[String]) -> Parsed {
 }
 
 impl Map {
-    fn map(&self, seed: usize) -> usize {
+    fn map(&self, seed: i64) -> i64 {
         for range in &self.ranges {
             if range.contains(seed) {
                 return range.dest + seed - range.src;
@@ -93,10 +84,24 @@ impl Map {
         }
         seed
     }
+
+    /// Map every seed of the set at once, each range moving its part of the set and the seeds
+    /// outside of every range keeping their value.
+    fn map_set(&self, seeds: &IntervalSet<i64>) -> IntervalSet<i64> {
+        let mut mapped = IntervalSet::new();
+        let mut remaining = seeds.clone();
+        for range in &self.ranges {
+            let source = IntervalSet::from(range.src..range.src + range.len);
+            let moved = remaining.intersection(&source);
+            remaining = remaining.difference(&source);
+            mapped = mapped.union(&moved.shift(range.dest - range.src));
+        }
+        mapped.union(&remaining)
+    }
 }
 
 impl Range {
-    fn contains(&self, value: usize) -> bool {
+    fn contains(&self, value: i64) -> bool {
         value >= self.src && value < (self.src + self.len)
     }
 }
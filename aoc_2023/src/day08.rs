@@ -1,15 +1,14 @@
-use std::collections::HashMap;
-
-use aoc_lib::{answer::Answer, maths, solution::Solution};
+use aoc_lib::{answer::Answer, graph::Graph, maths, solution::Solution};
 
 pub struct Day8;
 
 impl Solution for Day8 {
     fn part_a(&self, input: &[String]) -> Answer {
         let parsed = parse(input);
-        let mut current_node = "AAA";
+        let mut current_node = parsed.network.id(&"AAA").unwrap();
+        let goal = parsed.network.id(&"ZZZ").unwrap();
         for (count, direction) in parsed.instructions.iter().cycle().enumerate() {
-            if current_node == "ZZZ" {
+            if current_node == goal {
                 return count.into();
             }
             current_node = parsed.get(current_node, direction);
@@ -19,39 +18,37 @@ impl Solution for Day8 {
 
     fn part_b(&self, input: &[String]) -> Answer {
         let parsed = parse(input);
-        let starting_nodes: Vec<&str> = parsed
-            .network
-            .iter()
-            .filter(|(&k, _)| k.ends_with('A'))
-            .map(|(&k, _)| k)
-            .collect();
+        let network = &parsed.network;
+        let starting_nodes = network
+            .nodes()
+            .filter(|&id| network.label(id).ends_with('A'));
         let mut cycles = vec![];
         for node in starting_nodes {
             let mut current_node = node;
             for (cycle_len, direction) in parsed.instructions.iter().cycle().enumerate() {
-                if current_node.ends_with('Z') {
+                if network.label(current_node).ends_with('Z') {
                     cycles.push(cycle_len as i64);
                     break;
                 }
                 current_node = parsed.get(current_node, direction);
             }
         }
-        let lcm = cycles.iter().copied().reduce(maths::lcm).unwrap_or(1);
-        lcm.into()
+        maths::lcm_all(cycles).into()
     }
 }
 
 struct Parsed<'a> {
     instructions: Vec<char>,
-    network: HashMap<&'a str, (&'a str, &'a str)>,
+    // the left then the right node are the two edges going out of each node
+    network: Graph<&'a str>,
 }
 
-impl<'a> Parsed<'a> {
-    fn get(&self, el: &'a str, direction: &char) -> &'a str {
-        let entry = self.network.get(el).unwrap();
+impl Parsed<'_> {
+    fn get(&self, el: usize, direction: &char) -> usize {
+        let entry = self.network.neighbors(el);
         match direction {
-            'L' => entry.0,
-            'R' => entry.1,
+            'L' => entry[0],
+            'R' => entry[1],
             _ => unreachable!(),
         }
     }
@@ -69,13 +66,14 @@ fn parse(input: &[String]) -> Parsed<'_> {
         .iter()
         .flat_map(|s| s.chars())
         .collect::<Vec<_>>();
-    let mut network = HashMap::new();
+    let mut network = Graph::directed();
     for line in nodes.iter().skip(1) {
         let (node, other) = line.split_once('=').unwrap();
         let (left, right) = other.trim().split_once(',').unwrap();
         let left = &left[1..];
         let right = &right[..right.len() - 1];
-        network.insert(node.trim(), (left.trim(), right.trim()));
+        network.add_edge(node.trim(), left.trim());
+        network.add_edge(node.trim(), right.trim());
     }
     Parsed {
         instructions,
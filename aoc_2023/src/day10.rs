@@ -1,6 +1,7 @@
 use aoc_lib::{
     answer::Answer,
     directions::{Advance, Cardinal, Direction},
+    geometry,
     matrix::Matrix,
     solution::Solution,
     vec2::Vec2,
@@ -16,20 +17,12 @@ impl Solution for Day10 {
 
     fn part_b(&self, input: &[String]) -> Answer {
         let grid = parse(input);
-        // use of the shoelace polygon area formula
-        let mut path = grid.walk();
-        path.push(grid.start_tile.position);
-        let len = path.len();
-        let area = path
-            .iter()
-            .enumerate()
-            .fold(0i32, |acc, (i, p)| {
-                let l = (i + 1) % len;
-                acc + (p.x as i32 * path[l].y as i32 - p.y as i32 * path[l].x as i32)
-            })
-            .abs()
-            / 2;
-        (area - (len as i32 / 2) + 1).into()
+        let path = grid
+            .walk()
+            .into_iter()
+            .map(Vec2::<isize>::from)
+            .collect::<Vec<_>>();
+        geometry::interior_points(&path).into()
     }
 }
 
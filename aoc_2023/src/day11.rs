@@ -3,7 +3,7 @@ use std::{
     collections::HashSet,
 };
 
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, matrix::Matrix, solution::Solution};
 
 pub struct Day11;
 
@@ -27,13 +27,13 @@ fn solve(input: &[String], expansion_factor: usize) -> Answer {
 }
 
 struct Sky {
-    elements: Vec<Vec<Element>>,
+    elements: Matrix<Element>,
 }
 
 impl Sky {
     fn retrieve_galaxies_positions(&self) -> Vec<(usize, usize)> {
         let mut galaxies_pos = vec![];
-        for (i, row) in self.elements.iter().enumerate() {
+        for (i, row) in self.elements.iter_rows().enumerate() {
             for (j, el) in row.iter().enumerate() {
                 if &Element::Galaxy == el {
                     galaxies_pos.push((j, i));
@@ -97,13 +97,14 @@ impl Sky {
     }
 
     fn row_empty(&self, position: usize) -> bool {
-        self.elements[position].iter().all(|e| *e == Element::Empty)
+        self.elements
+            .row(position)
+            .iter()
+            .all(|e| *e == Element::Empty)
     }
 
     fn col_empty(&self, position: usize) -> bool {
-        self.elements
-            .iter()
-            .all(|row| row[position] == Element::Empty)
+        self.elements.column(position).all(|e| *e == Element::Empty)
     }
 }
 
@@ -120,7 +121,9 @@ fn parse(input: &[String]) -> Sky {
         }
         elements.push(temp);
     }
-    Sky { elements }
+    Sky {
+        elements: Matrix::from(elements),
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
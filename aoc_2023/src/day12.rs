@@ -1,9 +1,6 @@
-use std::{
-    collections::HashMap,
-    fmt::{Display, Write},
-};
+use std::fmt::{Display, Write};
 
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, memo::Memo, solution::Solution};
 
 pub struct Day12;
 
@@ -64,46 +61,45 @@ struct Row {
 impl Row {
     fn count_arrangements(&self) -> usize {
         fn dfs(
-            memo: &mut HashMap<(usize, usize, usize), usize>,
+            memo: &mut Memo<(usize, usize, usize), usize>,
             row: &Row,
             pos: usize,
             group_index: usize,
             group_len: usize,
         ) -> usize {
-            if let Some(&key) = memo.get(&(pos, group_index, group_len)) {
-                return key;
-            }
-            let mut arrangements = 0;
-            if pos == row.conditions.len() {
-                arrangements = (group_index == row.group_sizes.len()) as usize;
-            } else if row.conditions[pos] == SpringCondition::Broken {
-                arrangements = dfs(memo, row, pos + 1, group_index, group_len + 1)
-            } else if row.conditions[pos] == SpringCondition::Working
-                || group_index == row.group_sizes.len()
-            {
-                if group_index < row.group_sizes.len() && group_len == row.group_sizes[group_index]
+            memo.get_or_compute((pos, group_index, group_len), |memo| {
+                let mut arrangements = 0;
+                if pos == row.conditions.len() {
+                    arrangements = (group_index == row.group_sizes.len()) as usize;
+                } else if row.conditions[pos] == SpringCondition::Broken {
+                    arrangements = dfs(memo, row, pos + 1, group_index, group_len + 1)
+                } else if row.conditions[pos] == SpringCondition::Working
+                    || group_index == row.group_sizes.len()
                 {
-                    // closing the block
-                    arrangements = dfs(memo, row, pos + 1, group_index + 1, 0);
-                } else if group_len == 0 {
-                    // multiple working..
-                    arrangements = dfs(memo, row, pos + 1, group_index, 0);
+                    if group_index < row.group_sizes.len()
+                        && group_len == row.group_sizes[group_index]
+                    {
+                        // closing the block
+                        arrangements = dfs(memo, row, pos + 1, group_index + 1, 0);
+                    } else if group_len == 0 {
+                        // multiple working..
+                        arrangements = dfs(memo, row, pos + 1, group_index, 0);
+                    }
+                } else {
+                    // continue with broken spring
+                    arrangements += dfs(memo, row, pos + 1, group_index, group_len + 1);
+
+                    // finished the block, closing
+                    if group_len == row.group_sizes[group_index] {
+                        arrangements += dfs(memo, row, pos + 1, group_index + 1, 0);
+                    } else if group_len == 0 {
+                        arrangements += dfs(memo, row, pos + 1, group_index, 0);
+                    }
                 }
-            } else {
-                // continue with broken spring
-                arrangements += dfs(memo, row, pos + 1, group_index, group_len + 1);
-
-                // finished the block, closing
-                if group_len == row.group_sizes[group_index] {
-                    arrangements += dfs(memo, row, pos + 1, group_index + 1, 0);
-                } else if group_len == 0 {
-                    arrangements += dfs(memo, row, pos + 1, group_index, 0);
-                }
-            }
-            memo.insert((pos, group_index, group_len), arrangements);
-            arrangements
+                arrangements
+            })
         }
-        dfs(&mut HashMap::new(), self, 0, 0, 0)
+        dfs(&mut Memo::new(), self, 0, 0, 0)
     }
 
     fn expand(&self) -> Self {
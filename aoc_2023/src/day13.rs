@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, matrix::Matrix, solution::Solution};
 
 pub struct Day13;
 
@@ -11,8 +11,8 @@ impl Solution for Day13 {
             .patterns
             .iter()
             .map(|p| {
-                Pattern::count_before_reflection(&p.transpose(), 0)
-                    + 100 * Pattern::count_before_reflection(&p.rows, 0)
+                Pattern::count_before_reflection(&p.grid.transpose(), 0)
+                    + 100 * Pattern::count_before_reflection(&p.grid, 0)
             })
             .sum::<usize>()
             .into()
@@ -24,8 +24,8 @@ impl Solution for Day13 {
             .patterns
             .iter()
             .map(|p| {
-                Pattern::count_before_reflection(&p.transpose(), 1)
-                    + 100 * Pattern::count_before_reflection(&p.rows, 1)
+                Pattern::count_before_reflection(&p.grid.transpose(), 1)
+                    + 100 * Pattern::count_before_reflection(&p.grid, 1)
             })
             .sum::<usize>()
             .into()
@@ -46,36 +46,30 @@ fn parse(input: &[String]) -> Parsed {
         .map(|(_, group)| group.collect::<Vec<_>>())
         .collect::<Vec<_>>();
     for group in degrouped {
-        let rows = group
-            .iter()
-            .map(|l| l.chars().collect::<Vec<char>>())
-            .collect::<Vec<Vec<_>>>();
-        patterns.push(Pattern { rows });
+        let grid = Matrix::from_chars(&group.into_iter().cloned().collect::<Vec<_>>());
+        patterns.push(Pattern { grid });
     }
     Parsed { patterns }
 }
 
 struct Pattern {
-    rows: Vec<Vec<char>>,
+    grid: Matrix<char>,
 }
 
 impl Pattern {
-    fn transpose(&self) -> Vec<Vec<char>> {
-        let rows = self.rows.clone();
-        (0..rows[0].len())
-            .map(|col| (0..rows.len()).map(|row| rows[row][col]).collect())
-            .collect()
-    }
-
-    fn count_before_reflection(buffer: &[Vec<char>], limit: usize) -> usize {
-        for mid in 1..=buffer.len() - 1 {
-            let side = mid.min(buffer.len() - mid);
+    /// Number of rows above the horizontal mirror having exactly `limit` smudges.
+    fn count_before_reflection(grid: &Matrix<char>, limit: usize) -> usize {
+        for mid in 1..=grid.rows - 1 {
+            let side = mid.min(grid.rows - mid);
             let start = mid - side;
             let mut diff = 0;
             for l in start..mid {
                 let r = mid * 2 - l - 1;
-                diff += (0..buffer[l].len())
-                    .filter(|&i| buffer[l][i] != buffer[r][i])
+                diff += grid
+                    .row(l)
+                    .iter()
+                    .zip(grid.row(r))
+                    .filter(|(a, b)| a != b)
                     .count();
             }
             if diff == limit {
@@ -1,7 +1,6 @@
-use std::collections::HashMap;
-
 use aoc_lib::{
     answer::Answer,
+    cycle,
     directions::{Cardinal, Direction},
     matrix::Matrix,
     solution::Solution,
@@ -18,19 +17,17 @@ impl Solution for Day14 {
     }
 
     fn part_b(&self, input: &[String]) -> Answer {
-        let mut plateform = parse(input);
-        const ITERATIONS: usize = 1000000000;
-        let mut seen = HashMap::new();
-        for i in 0..ITERATIONS {
-            if let Some(previous) = seen.get(&plateform) {
-                if (ITERATIONS - i).is_multiple_of(i - previous) {
-                    return plateform.score().into();
-                }
-            }
-            seen.insert(plateform.clone(), i);
-            plateform.tilt_cycle();
-        }
-        plateform.score().into()
+        let plateform = parse(input);
+        let tilted = cycle::nth(
+            plateform,
+            |plateform| {
+                let mut plateform = plateform.clone();
+                plateform.tilt_cycle();
+                plateform
+            },
+            1000000000,
+        );
+        tilted.score().into()
     }
 }
 
@@ -1,6 +1,7 @@
 use aoc_lib::{
     answer::Answer,
     directions::{Cardinal, Direction},
+    geometry,
     solution::Solution,
     vec2::Vec2,
 };
@@ -21,20 +22,13 @@ impl Solution for Day18 {
 
 fn solve(instructions: Vec<Instruction>) -> isize {
     let mut pos = Vec2::new(0_isize, 0_isize);
-    let mut perimeter = 0;
-    let mut area = 0;
+    let mut vertices = vec![];
     for instruction in instructions {
-        let step = instruction.len as isize;
-        perimeter += step;
-        let after = instruction.direction.to_offset() * step;
-        pos += after;
-        area += pos.x * after.y;
+        pos += instruction.direction.to_offset() * instruction.len as isize;
+        vertices.push(pos);
     }
-    // number of lava cubes in the trench = inside points + outside points
-    // 1) inside points = pick's theorem (I = A - P/2 + 1)
-    // 2) outside points = P
-    // <=> number of lava cubes = A + P/2 + 1
-    area + perimeter / 2 + 1
+    // number of lava cubes in the trench = outside points + inside points
+    geometry::boundary_points(&vertices) + geometry::interior_points(&vertices)
 }
 
 #[derive(Debug)]
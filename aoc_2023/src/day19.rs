@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, error::AocError, interval::IntervalSet, solution::FallibleSolution};
 
 pub struct Day19;
 
-impl Solution for Day19 {
-    fn part_a(&self, input: &[String]) -> Answer {
-        let parsed = parse(input);
+impl FallibleSolution for Day19 {
+    type Parsed = Parsed;
+
+    fn parse(&self, input: &[String]) -> Result<Self::Parsed, AocError> {
+        parse(input)
+    }
+
+    fn part_a(&self, parsed: &Self::Parsed) -> Result<Answer, AocError> {
         let mut sum = 0;
-        for part in parsed.parts {
+        for part in &parsed.parts {
             let mut current_workflow = "in".to_owned();
             loop {
                 if current_workflow == *"R" {
@@ -20,7 +25,7 @@ impl Solution for Day19 {
                 }
                 for rule in parsed.workflows.get(&current_workflow).unwrap() {
                     if let Some(condition) = &rule.condition {
-                        if condition.valid(&part) {
+                        if condition.valid(part) {
                             current_workflow = rule.next_workflow.clone();
                             break;
                         }
@@ -30,17 +35,17 @@ impl Solution for Day19 {
                 }
             }
         }
-        sum.into()
+        Ok(sum.into())
     }
 
-    fn part_b(&self, input: &[String]) -> Answer {
-        let parsed = parse(input);
-        solve_b([(1, 4000); 4], &parsed.workflows, "in".to_owned()).into()
+    fn part_b(&self, parsed: &Self::Parsed) -> Result<Answer, AocError> {
+        let ratings = std::array::from_fn(|_| IntervalSet::from(1..4001));
+        Ok(solve_b(ratings, &parsed.workflows, "in".to_owned()).into())
     }
 }
 
 fn solve_b(
-    mut ranges: [(usize, usize); 4],
+    mut ratings: [IntervalSet<usize>; 4],
     workflows: &HashMap<String, Vec<Rule>>,
     current_workflow: String,
 ) -> usize {
@@ -50,81 +55,114 @@ fn solve_b(
         return sum;
     }
     if *current_workflow == *"A" {
-        return sum + count_ranges_combinations(&ranges);
+        return sum + ratings.iter().map(IntervalSet::len).product::<usize>();
     }
     for rule in workflows.get(&current_workflow).unwrap() {
         if let Some(condition) = &rule.condition {
             let index = Part::to_rating_index(condition.category);
-            let mut valid_ranges = ranges;
-            let valid_rating = &mut valid_ranges[index];
-            let invalid_rating = &mut ranges[index];
-
-            match condition.condition_type {
-                ConditionType::LessThan if valid_rating.0 < condition.value => {
-                    valid_rating.1 = valid_rating.1.min(condition.value - 1);
-                    invalid_rating.0 = invalid_rating.0.max(condition.value);
+            let (valid, invalid) = match condition.condition_type {
+                ConditionType::LessThan => ratings[index].split_at(condition.value),
+                ConditionType::GreaterThan => {
+                    let (below, above) = ratings[index].split_at(condition.value + 1);
+                    (above, below)
                 }
-                ConditionType::GreaterThan if valid_rating.1 > condition.value => {
-                    valid_rating.0 = valid_rating.0.max(condition.value + 1);
-                    invalid_rating.1 = invalid_rating.1.min(condition.value);
-                }
-                _ => continue,
             };
-
-            // we explore with new valid range
-            sum += solve_b(valid_ranges, workflows, rule.next_workflow.clone());
+            if !valid.is_empty() {
+                // we explore with new valid range
+                let mut valid_ratings = ratings.clone();
+                valid_ratings[index] = valid;
+                sum += solve_b(valid_ratings, workflows, rule.next_workflow.clone());
+            }
+            ratings[index] = invalid;
         } else {
-            sum += solve_b(ranges, workflows, rule.next_workflow.clone())
+            sum += solve_b(ratings.clone(), workflows, rule.next_workflow.clone())
         }
     }
     sum
 }
 
-fn parse(input: &[String]) -> Parsed {
-    let mut split = input.split(|line| line.trim().is_empty());
-    let workflows_str = split.next().unwrap_or(&[]).to_vec();
-    let parts_str = split.next().unwrap_or(&[]).to_vec();
-    let mut parts = vec![];
+fn parse(input: &[String]) -> Result<Parsed, AocError> {
+    let mut lines = input.iter().enumerate();
     let mut workflows = HashMap::new();
-    for workflow in workflows_str {
-        let (name, rule) = workflow.split_once('{').unwrap();
-        let mut rules = vec![];
-        for r in rule[..rule.len() - 1].split(',') {
+    let mut targets = vec![];
+    for (i, line) in lines.by_ref() {
+        if line.trim().is_empty() {
+            break;
+        }
+        let (name, rules) = parse_workflow(line)
+            .ok_or_else(|| AocError::at_line(i, "expected `name{a<2006:qkq,..,rfg}`"))?;
+        targets.extend(rules.iter().map(|r| (i, r.next_workflow.clone())));
+        workflows.insert(name, rules);
+    }
+    // every rule must lead to a known workflow, so that solving never gets stuck
+    for (i, target) in targets {
+        if target != "A" && target != "R" && !workflows.contains_key(&target) {
+            return Err(AocError::at_line(i, format!("unknown workflow `{target}`")));
+        }
+    }
+    if !workflows.contains_key("in") {
+        return Err(AocError::new("missing the `in` workflow"));
+    }
+
+    let mut parts = vec![];
+    for (i, line) in lines {
+        if line.trim().is_empty() {
+            continue;
+        }
+        parts.push(
+            parse_part(line)
+                .ok_or_else(|| AocError::at_line(i, "expected `{x=..,m=..,a=..,s=..}`"))?,
+        );
+    }
+    Ok(Parsed { workflows, parts })
+}
+
+fn parse_workflow(line: &str) -> Option<(String, Vec<Rule>)> {
+    let (name, rules) = line.strip_suffix('}')?.split_once('{')?;
+    let rules = rules
+        .split(',')
+        .map(|r| {
             let Some((condition, next_workflow)) = r.split_once(':') else {
-                rules.push(Rule {
+                return Some(Rule {
                     condition: None,
                     next_workflow: r.to_string(),
                 });
-                continue;
             };
-            let condition_type = ConditionType::from(&condition[1..2]);
-            let value = condition[2..].parse::<usize>().unwrap();
-            rules.push(Rule {
+            let mut chars = condition.chars();
+            let category = chars.next().filter(|c| "xmas".contains(*c))?;
+            let condition_type = match chars.next()? {
+                '>' => ConditionType::GreaterThan,
+                '<' => ConditionType::LessThan,
+                _ => return None,
+            };
+            let value = chars.as_str().parse::<usize>().ok()?;
+            Some(Rule {
                 condition: Some(Condition {
-                    category: condition[0..1].chars().next().unwrap(),
+                    category,
                     condition_type,
                     value,
                 }),
                 next_workflow: next_workflow.to_string(),
             })
-        }
-        workflows.insert(name.to_string(), rules);
-    }
-    for part in parts_str {
-        let part = part[1..part.len() - 1].to_owned();
-        let ratings_str = part.split(',').collect::<Vec<_>>();
-        let mut ratings = [0; 4];
-        for token in ratings_str {
-            let (rating, value) = token.split_once('=').unwrap();
-            let rating = rating.chars().next().unwrap();
-            ratings[Part::to_rating_index(rating)] = value.parse::<usize>().unwrap();
-        }
-        parts.push(Part { ratings });
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((name.to_string(), rules))
+}
+
+fn parse_part(line: &str) -> Option<Part> {
+    let mut ratings = [0; 4];
+    for token in line.strip_prefix('{')?.strip_suffix('}')?.split(',') {
+        let (rating, value) = token.split_once('=')?;
+        let index = match rating {
+            "x" | "m" | "a" | "s" => Part::to_rating_index(rating.chars().next()?),
+            _ => return None,
+        };
+        ratings[index] = value.parse::<usize>().ok()?;
     }
-    Parsed { workflows, parts }
+    Some(Part { ratings })
 }
 
-struct Parsed {
+pub struct Parsed {
     workflows: HashMap<String, Vec<Rule>>,
     parts: Vec<Part>,
 }
@@ -161,16 +199,6 @@ enum ConditionType {
     LessThan,
 }
 
-impl From<&str> for ConditionType {
-    fn from(value: &str) -> Self {
-        match value {
-            ">" => ConditionType::GreaterThan,
-            "<" => ConditionType::LessThan,
-            _ => unreachable!(),
-        }
-    }
-}
-
 #[derive(Debug)]
 struct Part {
     ratings: [usize; 4],
@@ -188,13 +216,9 @@ impl Part {
     }
 }
 
-fn count_ranges_combinations(ranges: &[(usize, usize); 4]) -> usize {
-    ranges.iter().map(|r| r.1 - r.0 + 1).product::<usize>()
-}
-
 #[cfg(test)]
 mod test {
-    use aoc_lib::{self, answer::Answer, input, solution::Solution};
+    use aoc_lib::{self, answer::Answer, input, solution::FallibleSolution};
 
     use super::Day19;
 
@@ -202,7 +226,7 @@ mod test {
     fn test_a() {
         let input =
             input::read_file(&format!("{}day_19_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-        let answer = Day19.part_a(&input);
+        let answer = Day19.part_a(&Day19.parse(&input).unwrap()).unwrap();
         assert_eq!(<i32 as Into<Answer>>::into(19114), answer);
     }
 
@@ -210,7 +234,20 @@ mod test {
     fn test_b() {
         let input =
             input::read_file(&format!("{}day_19_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-        let answer = Day19.part_b(&input);
+        let answer = Day19.part_b(&Day19.parse(&input).unwrap()).unwrap();
         assert_eq!(<i64 as Into<Answer>>::into(167409079868000), answer);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = ["in{x<10:A,R}", "", "{x=1,m=2,a=3,s=4}", "{x=1,m=2}a"].map(String::from);
+        let error = Day19.parse(&input).err().unwrap();
+        assert_eq!(
+            "line 4: expected `{x=..,m=..,a=..,s=..}`",
+            error.to_string()
+        );
+        let input = ["in{x<10:px,R}"].map(String::from);
+        let error = Day19.parse(&input).err().unwrap();
+        assert_eq!("line 1: unknown workflow `px`", error.to_string());
+    }
 }
@@ -3,11 +3,8 @@ use std::collections::HashSet;
 use polynomial::Polynomial;
 
 use aoc_lib::{
-    answer::Answer,
-    directions::{Cardinal, Direction},
-    matrix::Matrix,
-    solution::Solution,
-    vec2::Vec2,
+    answer::Answer, directions::Cardinal, matrix::Matrix, solution::Solution,
+    sparse_grid::SparseGrid, vec2::Vec2,
 };
 
 pub struct Day21;
@@ -33,18 +30,19 @@ impl Solution for Day21 {
 
     fn part_b(&self, input: &[String]) -> Answer {
         let parsed = parse(input);
-        let mut queue = HashSet::new();
-        queue.insert(Vec2::<isize>::from(parsed.starting_pos));
+        // the reached plots spread over the infinitely repeated map
+        let mut queue = SparseGrid::new();
+        queue.insert(Vec2::<isize>::from(parsed.starting_pos), ());
 
         let map = &parsed.map;
         let mut points = vec![];
         let mut steps = 0;
         for run in 0..3 {
             while steps < (65 + run * SIZE) {
-                let mut next = HashSet::new();
-                for pos in queue.iter() {
+                let mut next = SparseGrid::new();
+                for pos in queue.positions() {
                     for t in next_tiles_scaled(map, *pos) {
-                        next.insert(t);
+                        next.insert(t, ());
                     }
                 }
                 queue = next;
@@ -71,42 +69,18 @@ fn parse(input: &[String]) -> Parsed {
 }
 
 fn next_tiles(map: &Matrix<char>, pos: Vec2<usize>) -> Vec<Vec2<usize>> {
-    let mut possible = vec![];
-    for direction in Cardinal::all_clockwise() {
-        let next_pos = Vec2::<isize>::from(pos) + direction.to_offset();
-        let next_tile = map.get(&next_pos);
-        if let Some(next_tile) = next_tile {
-            let next_pos = Vec2::<usize>::try_from(&next_pos).unwrap();
-            if *next_tile != '#' {
-                possible.push(next_pos);
-            }
-        }
-    }
-    possible
+    map.neighbors::<Cardinal>(pos)
+        .filter(|(_, &tile)| tile != '#')
+        .map(|(next_pos, _)| next_pos)
+        .collect()
 }
 
 fn next_tiles_scaled(map: &Matrix<char>, pos: Vec2<isize>) -> Vec<Vec2<isize>> {
-    let mut possible = vec![];
-    for direction in Cardinal::all_clockwise() {
-        let next_pos = pos + direction.to_offset();
-        // scaled is to virtually check on the real map
-        let scaled = scale_pos(next_pos);
-        let next_tile = map[scaled];
-        if next_tile != '#' {
-            possible.push(next_pos);
-        }
-    }
-    possible
-}
-
-fn scale_pos(pos: Vec2<isize>) -> Vec2<usize> {
-    let mut mapped = pos;
-    // only want
-    mapped = Vec2::new(
-        (SIZE as isize + mapped.x % SIZE as isize) % SIZE as isize,
-        (SIZE as isize + mapped.y % SIZE as isize) % SIZE as isize,
-    );
-    Vec2::<usize>::try_from(&mapped).unwrap()
+    // the map repeats infinitely, tiles are read from the real map
+    map.neighbors_wrapping::<Cardinal>(pos)
+        .filter(|(_, &tile)| tile != '#')
+        .map(|(next_pos, _)| next_pos)
+        .collect()
 }
 
 #[cfg(test)]
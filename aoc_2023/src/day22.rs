@@ -12,9 +12,7 @@ impl Solution for Day22 {
     fn part_a(&self, input: &[String]) -> Answer {
         let mut playground = parse(input);
         // sort bricks by z value to start by the bottom
-        playground
-            .bricks
-            .sort_unstable_by(|a, b| a.end.2.cmp(&b.end.2));
+        playground.bricks.sort_unstable_by_key(|b| b.end.2);
         let brick_at_levels = playground.release_bricks();
         let upper_to_base_bricks = get_upper_to_base_bricks(&playground.bricks, brick_at_levels);
         let critical = upper_to_base_bricks.values().filter(|b| b.len() == 1).fold(
@@ -30,9 +28,7 @@ impl Solution for Day22 {
     fn part_b(&self, input: &[String]) -> Answer {
         let mut playground = parse(input);
         // sort bricks by z value to start by the bottom
-        playground
-            .bricks
-            .sort_unstable_by(|a, b| a.end.2.cmp(&b.end.2));
+        playground.bricks.sort_unstable_by_key(|b| b.end.2);
         let brick_at_levels = playground.release_bricks();
         let upper_to_base_bricks = get_upper_to_base_bricks(&playground.bricks, brick_at_levels);
 
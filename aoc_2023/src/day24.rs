@@ -1,175 +1,162 @@
-// use itertools::Itertools;
-// use z3::{
-//     ast::{Ast, Int},
-//     Config, Context, SatResult, Solver,
-// };
-//
-// use aoc_lib::{answer::Answer, solution::Solution, vec2::Vec2};
-// use core::ops::RangeInclusive;
-//
-// pub struct Day24;
-//
-// impl Solution for Day24 {
-//     fn part_a(&self, input: &[String]) -> Answer {
-//         let hailstones = parse(input);
-//         solve_a(&hailstones, 200000000000000.0..=400000000000000.0).into()
-//     }
-//
-//     fn part_b(&self, input: &[String]) -> Answer {
-//         let hailstones = parse(input);
-//         solve_b(&hailstones).into()
-//     }
-// }
-//
-// fn solve_a(hailstones: &[Hailstone], range: RangeInclusive<f32>) -> usize {
-//     hailstones
-//         .iter()
-//         .tuple_combinations()
-//         .filter_map(|(a, b)| a.collision_point(b))
-//         .filter(|&pos| range.contains(&pos.x) && range.contains(&pos.y))
-//         .count()
-// }
-//
-// fn solve_b(hailstones: &[Hailstone]) -> i64 {
-//     let config = Config::new();
-//     let ctx = Context::new(&config);
-//     let solver = Solver::new(&ctx);
-//
-//     let (xr, yr, zr, vxr, vyr, vzr) = (
-//         Int::new_const(&ctx, "xr"),
-//         Int::new_const(&ctx, "yr"),
-//         Int::new_const(&ctx, "zr"),
-//         Int::new_const(&ctx, "vxr"),
-//         Int::new_const(&ctx, "vyr"),
-//         Int::new_const(&ctx, "vzr"),
-//     );
-//
-//     for hailstone in hailstones {
-//         let (xh, yh, zh, vxh, vyh, vzh, t) = (
-//             Int::from_i64(&ctx, hailstone.position.0 as i64),
-//             Int::from_i64(&ctx, hailstone.position.1 as i64),
-//             Int::from_i64(&ctx, hailstone.position.2 as i64),
-//             Int::from_i64(&ctx, hailstone.velocity.0 as i64),
-//             Int::from_i64(&ctx, hailstone.velocity.1 as i64),
-//             Int::from_i64(&ctx, hailstone.velocity.2 as i64),
-//             Int::new_const(&ctx, format!("{}", hailstone.id)),
-//         );
-//         solver.assert(&((&vxr - &vxh) * &t)._eq(&(&xh - &xr)));
-//         solver.assert(&((&vyr - &vyh) * &t)._eq(&(&yh - &yr)));
-//         solver.assert(&((&vzr - &vzh) * &t)._eq(&(&zh - &zr)));
-//     }
-//
-//     // The problem has at least one solution.
-//     assert!(solver.check() == SatResult::Sat);
-//
-//     let model = solver.get_model().unwrap();
-//     let xr_value = model.eval(&xr, false).unwrap();
-//     let yr_value = model.eval(&yr, false).unwrap();
-//     let zr_value = model.eval(&zr, false).unwrap();
-//     xr_value.as_i64().unwrap() + yr_value.as_i64().unwrap() + zr_value.as_i64().unwrap()
-// }
-//
-// fn parse(input: &[String]) -> Vec<Hailstone> {
-//     let mut hailstones = vec![];
-//     for (id, line) in input.iter().enumerate() {
-//         let (position, velocity) = line.split_once('@').unwrap();
-//         let position = position
-//             .split(',')
-//             .map(|p| p.trim().parse::<isize>().unwrap())
-//             .collect::<Vec<_>>();
-//         let velocity = velocity
-//             .split(',')
-//             .map(|p| p.trim().parse::<isize>().unwrap())
-//             .collect::<Vec<_>>();
-//         hailstones.push(Hailstone {
-//             position: (position[0], position[1], position[2]),
-//             velocity: (velocity[0], velocity[1], velocity[2]),
-//             id,
-//         });
-//     }
-//     hailstones
-// }
-//
-// struct HailstonePath {
-//     slope: f32,
-//     intercept: f32,
-// }
-//
-// impl HailstonePath {
-//     fn from(hailstone: &Hailstone) -> Self {
-//         let n = Vec2::new(hailstone.position.0, hailstone.position.1);
-//         let velocity = Vec2::new(hailstone.velocity.0, hailstone.velocity.1);
-//         let m = n + velocity;
-//         let slope = (m.y - n.y) as f32 / (m.x - n.x) as f32;
-//         let intercept = n.y as f32 - (slope * n.x as f32);
-//         Self { slope, intercept }
-//     }
-//
-//     // return the x value where the two paths intersect
-//     fn intersection(&self, other: &HailstonePath) -> f32 {
-//         (other.intercept - self.intercept) / (self.slope - other.slope)
-//     }
-//
-//     fn evaluate(&self, x: f32) -> f32 {
-//         self.slope * x + self.intercept
-//     }
-// }
-//
-// #[derive(PartialEq)]
-// struct Hailstone {
-//     position: (isize, isize, isize),
-//     // velocity is given as (dx, dy, dz) each unit of time.
-//     velocity: (isize, isize, isize),
-//     // for z3 bindings, if a const have the same name it cause the model to be unsat...
-//     // because the internal representation of the consts might depend on the string named passed to
-//     // new_consts(). Used to name the const `t` (time of collision) for every hailstone
-//     id: usize,
-// }
-//
-// impl Hailstone {
-//     fn collision_point(&self, other: &Hailstone) -> Option<Vec2<f32>> {
-//         let u = HailstonePath::from(self);
-//         let v = HailstonePath::from(other);
-//
-//         // will never inteserct
-//         if u.slope == v.slope {
-//             return None;
-//         }
-//
-//         let x_int = u.intersection(&v);
-//         let y_int = u.evaluate(x_int);
-//
-//         let t_curr = (x_int - self.position.0 as f32) / self.velocity.0 as f32;
-//         let t_other = (x_int - other.position.0 as f32) / other.velocity.0 as f32;
-//
-//         // intersect in the past
-//         if t_curr <= 0.0 || t_other <= 0.0 {
-//             return None;
-//         }
-//
-//         Some(Vec2::new(x_int, y_int))
-//     }
-// }
-//
-// #[cfg(test)]
-// mod test {
-//     use aoc_lib::{self, answer::Answer, input, solution::Solution};
-//
-//     use super::{parse, solve_a, Day24};
-//
-//     #[test]
-//     fn test_a() {
-//         let input =
-//             input::read_file(&format!("{}day_24_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-//         let answer = solve_a(&parse(&input), 7.0..=27.0).into();
-//         assert_eq!(<i32 as Into<Answer>>::into(2), answer);
-//     }
-//
-//     #[test]
-//     fn test_b() {
-//         let input =
-//             input::read_file(&format!("{}day_24_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-//         let answer = Day24.part_b(&input);
-//         assert_eq!(<i32 as Into<Answer>>::into(47), answer);
-//     }
-// }
+use itertools::Itertools;
+use num::{BigInt, ToPrimitive};
+
+use aoc_lib::{
+    answer::Answer,
+    linear::{self, Rational},
+    solution::Solution,
+};
+use core::ops::RangeInclusive;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    fn part_a(&self, input: &[String]) -> Answer {
+        let hailstones = parse(input);
+        solve_a(&hailstones, 200000000000000..=400000000000000).into()
+    }
+
+    fn part_b(&self, input: &[String]) -> Answer {
+        let hailstones = parse(input);
+        solve_b(&hailstones).into()
+    }
+}
+
+fn solve_a(hailstones: &[Hailstone], range: RangeInclusive<i64>) -> usize {
+    let range = Rational::from(*range.start() as i128)..=Rational::from(*range.end() as i128);
+    hailstones
+        .iter()
+        .tuple_combinations()
+        .filter_map(|(a, b)| a.collision_point(b))
+        .filter(|(x, y)| range.contains(x) && range.contains(y))
+        .count()
+}
+
+/// The rock `(P, V)` hits every hailstone `(p, v)` at some time `t`, so `P - p = t (v - V)` and
+/// `(P - p) × (V - v) = 0`. The `P × V` term is the same for every hailstone, so subtracting the
+/// equations of two hailstones `i` and `j` gives three linear equations:
+/// `P × (v_j - v_i) + (p_j - p_i) × V = p_j × v_j - p_i × v_i`.
+/// Two pairs of hailstones are enough to find the six unknowns.
+fn solve_b(hailstones: &[Hailstone]) -> i64 {
+    hailstones
+        .iter()
+        .tuple_windows()
+        .find_map(|(a, b, c)| {
+            let mut rows = vec![];
+            let mut rhs = vec![];
+            for other in [b, c] {
+                let (equations, values) = a.rock_equations(other);
+                rows.extend(equations);
+                rhs.extend(values);
+            }
+            let rows = rows
+                .into_iter()
+                .map(|row| row.into_iter().map(BigInt::from).collect())
+                .collect::<Vec<Vec<_>>>();
+            let rhs = rhs.into_iter().map(BigInt::from).collect::<Vec<_>>();
+            linear::solve(&rows, &rhs)
+        })
+        .map(|rock| {
+            let position = rock[0].clone() + rock[1].clone() + rock[2].clone();
+            position.to_integer().to_i64().unwrap()
+        })
+        // The problem has at least one solution.
+        .unwrap()
+}
+
+fn parse(input: &[String]) -> Vec<Hailstone> {
+    let mut hailstones = vec![];
+    for line in input {
+        let (position, velocity) = line.split_once('@').unwrap();
+        let position = position
+            .split(',')
+            .map(|p| p.trim().parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+        let velocity = velocity
+            .split(',')
+            .map(|p| p.trim().parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+        hailstones.push(Hailstone {
+            position: [position[0], position[1], position[2]],
+            velocity: [velocity[0], velocity[1], velocity[2]],
+        });
+    }
+    hailstones
+}
+
+#[derive(PartialEq)]
+struct Hailstone {
+    position: [i64; 3],
+    // velocity is given as (dx, dy, dz) each unit of time.
+    velocity: [i64; 3],
+}
+
+impl Hailstone {
+    /// Where the paths of the two hailstones cross in the xy plane, if they cross in the future
+    /// of both.
+    fn collision_point(&self, other: &Hailstone) -> Option<(Rational, Rational)> {
+        let [px, py, _] = self.position.map(|v| v as i128);
+        let [vx, vy, _] = self.velocity.map(|v| v as i128);
+        let [qx, qy, _] = other.position.map(|v| v as i128);
+        let [ux, uy, _] = other.velocity.map(|v| v as i128);
+
+        // p + t * v = q + s * u, parallel paths never intersect
+        let times = linear::solve(&[vec![vx, -ux], vec![vy, -uy]], &[qx - px, qy - py])?;
+        let (t, s) = (times[0], times[1]);
+
+        // intersect in the past
+        if t <= Rational::from(0) || s <= Rational::from(0) {
+            return None;
+        }
+
+        Some((
+            Rational::from(px) + t * Rational::from(vx),
+            Rational::from(py) + t * Rational::from(vy),
+        ))
+    }
+
+    /// The three equations linking the rock position and velocity given by this hailstone and
+    /// `other`, as coefficients of `(X, Y, Z, VX, VY, VZ)` and right hand sides.
+    fn rock_equations(&self, other: &Hailstone) -> ([Vec<i128>; 3], [i128; 3]) {
+        let [pix, piy, piz] = self.position.map(|v| v as i128);
+        let [vix, viy, viz] = self.velocity.map(|v| v as i128);
+        let [pjx, pjy, pjz] = other.position.map(|v| v as i128);
+        let [vjx, vjy, vjz] = other.velocity.map(|v| v as i128);
+        let (dpx, dpy, dpz) = (pjx - pix, pjy - piy, pjz - piz);
+        let (dvx, dvy, dvz) = (vjx - vix, vjy - viy, vjz - viz);
+        let equations = [
+            vec![0, dvz, -dvy, 0, -dpz, dpy],
+            vec![-dvz, 0, dvx, dpz, 0, -dpx],
+            vec![dvy, -dvx, 0, -dpy, dpx, 0],
+        ];
+        let rhs = [
+            (pjy * vjz - pjz * vjy) - (piy * viz - piz * viy),
+            (pjz * vjx - pjx * vjz) - (piz * vix - pix * viz),
+            (pjx * vjy - pjy * vjx) - (pix * viy - piy * vix),
+        ];
+        (equations, rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_lib::{self, answer::Answer, input, solution::Solution};
+
+    use super::{parse, solve_a, Day24};
+
+    #[test]
+    fn test_a() {
+        let input =
+            input::read_file(&format!("{}day_24_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
+        let answer = solve_a(&parse(&input), 7..=27).into();
+        assert_eq!(<i32 as Into<Answer>>::into(2), answer);
+    }
+
+    #[test]
+    fn test_b() {
+        let input =
+            input::read_file(&format!("{}day_24_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
+        let answer = Day24.part_b(&input);
+        assert_eq!(<i32 as Into<Answer>>::into(47), answer);
+    }
+}
@@ -1,46 +1,33 @@
-use std::collections::HashMap;
+use aoc_lib::{answer::Answer, graph::Graph, solution::ParsedSolution};
 
-use petgraph::{Graph, Undirected};
-use rustworkx_core::connectivity::stoer_wagner_min_cut;
+pub struct Day25;
 
-use aoc_lib::{answer::Answer, solution::Solution};
+impl ParsedSolution for Day25 {
+    type Parsed = Graph<String>;
 
-pub struct Day25;
+    fn parse(&self, input: &[String]) -> Self::Parsed {
+        parse(input)
+    }
 
-impl Solution for Day25 {
-    fn part_a(&self, input: &[String]) -> Answer {
-        let graph = parse(input);
-        let total = graph.node_count();
-        let (len, side) = stoer_wagner_min_cut(&graph, |_| Ok::<i32, ()>(1))
-            .unwrap()
-            .unwrap();
+    fn part_a(&self, graph: &Self::Parsed) -> Answer {
+        let total = graph.len();
+        let (len, side) = graph.min_cut().unwrap();
         assert_eq!(3, len);
         // multiply the two parts together
         ((total - side.len()) * side.len()).into()
     }
 
-    fn part_b(&self, _: &[String]) -> Answer {
+    fn part_b(&self, _: &Self::Parsed) -> Answer {
         Answer::Unimplemented
     }
 }
 
-fn parse(input: &[String]) -> Graph<String, (), Undirected> {
-    let mut graph = Graph::new_undirected();
-    let mut nodes: HashMap<String, _> = HashMap::new();
-
+fn parse(input: &[String]) -> Graph<String> {
+    let mut graph = Graph::undirected();
     for line in input {
         let (left, right) = line.split_once(": ").unwrap();
-        let right_nodes = right.split(' ').collect::<Vec<_>>();
-
-        let left_id = *nodes
-            .entry(left.to_string())
-            .or_insert_with(|| graph.add_node(left.to_string()));
-
-        for n in &right_nodes {
-            let n_id = *nodes
-                .entry(n.to_string())
-                .or_insert_with(|| graph.add_node(n.to_string()));
-            graph.add_edge(left_id, n_id, ());
+        for n in right.split(' ') {
+            graph.add_edge(left.to_string(), n.to_string());
         }
     }
     graph
@@ -48,7 +35,7 @@ fn parse(input: &[String]) -> Graph<String, (), Undirected> {
 
 #[cfg(test)]
 mod test {
-    use aoc_lib::{answer::Answer, input, solution::Solution};
+    use aoc_lib::{answer::Answer, input, solution::ParsedSolution};
 
     use crate::day25::Day25;
 
@@ -56,7 +43,7 @@ mod test {
     fn test_a() {
         let input =
             input::read_file(&format!("{}day_25_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-        let answer = Day25.part_a(&input);
+        let answer = Day25.part_a(&Day25.parse(&input));
         assert_eq!(<i32 as Into<Answer>>::into(54), answer);
     }
 }
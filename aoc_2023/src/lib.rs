@@ -1,4 +1,4 @@
-use aoc_lib::solution::Solution;
+use aoc_lib::registry::Entry;
 
 pub mod day01;
 pub mod day02;
@@ -26,32 +26,34 @@ pub mod day23;
 pub mod day24;
 pub mod day25;
 
-pub const ALL: &[&dyn Solution] = &[
-    &day01::Day1,
-    &day02::Day2,
-    &day03::Day3,
-    &day04::Day4,
-    &day05::Day5,
-    &day06::Day6,
-    &day07::Day7,
-    &day08::Day8,
-    &day09::Day9,
-    &day10::Day10,
-    &day11::Day11,
-    &day12::Day12,
-    &day13::Day13,
-    &day14::Day14,
-    &day15::Day15,
-    &day16::Day16,
-    &day17::Day17,
-    &day18::Day18,
-    &day19::Day19,
-    &day20::Day20,
-    &day21::Day21,
-    &day22::Day22,
-    &day23::Day23,
-    //&day24::Day24,
-    &day25::Day25,
+pub const YEAR: u16 = 2023;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(YEAR, 1, "Trebuchet?!", &day01::Day1),
+    Entry::new(YEAR, 2, "Cube Conundrum", &day02::Day2),
+    Entry::new(YEAR, 3, "Gear Ratios", &day03::Day3),
+    Entry::new(YEAR, 4, "Scratchcards", &day04::Day4),
+    Entry::new(YEAR, 5, "If You Give A Seed A Fertilizer", &day05::Day5),
+    Entry::new(YEAR, 6, "Wait For It", &day06::Day6),
+    Entry::new(YEAR, 7, "Camel Cards", &day07::Day7),
+    Entry::new(YEAR, 8, "Haunted Wasteland", &day08::Day8),
+    Entry::new(YEAR, 9, "Mirage Maintenance", &day09::Day9),
+    Entry::new(YEAR, 10, "Pipe Maze", &day10::Day10),
+    Entry::new(YEAR, 11, "Cosmic Expansion", &day11::Day11),
+    Entry::new(YEAR, 12, "Hot Springs", &day12::Day12),
+    Entry::new(YEAR, 13, "Point of Incidence", &day13::Day13),
+    Entry::new(YEAR, 14, "Parabolic Reflector Dish", &day14::Day14),
+    Entry::new(YEAR, 15, "Lens Library", &day15::Day15),
+    Entry::new(YEAR, 16, "The Floor Will Be Lava", &day16::Day16),
+    Entry::new(YEAR, 17, "Clumsy Crucible", &day17::Day17),
+    Entry::new(YEAR, 18, "Lavaduct Lagoon", &day18::Day18),
+    Entry::new(YEAR, 19, "Aplenty", &day19::Day19),
+    Entry::new(YEAR, 20, "Pulse Propagation", &day20::Day20),
+    Entry::new(YEAR, 21, "Step Counter", &day21::Day21),
+    Entry::new(YEAR, 22, "Sand Slabs", &day22::Day22),
+    Entry::new(YEAR, 23, "A Long Walk", &day23::Day23),
+    Entry::new(YEAR, 24, "Never Tell Me The Odds", &day24::Day24),
+    Entry::new(YEAR, 25, "Snowverload", &day25::Day25),
 ];
 
 pub const FILES_PREFIX_TEST: &str = "resources/test/";
@@ -62,7 +62,7 @@ impl PrintQueue {
             let empty_set = HashSet::<i32>::new();
             let must_seen = self.order.get(&el).unwrap_or(&empty_set);
             let diff = must_seen.difference(&seen).collect::<HashSet<_>>();
-            invalids.extend(diff.into_iter());
+            invalids.extend(diff);
             seen.insert(el);
         }
         true
@@ -57,10 +57,7 @@ impl Map {
         let mut pos = Vec2::<isize>::from(self.starting_pos);
         let mut dir = Cardinal::North;
         let mut visited: HashSet<(Vec2<usize>, Cardinal)> = HashSet::new();
-        loop {
-            let Some(&ch) = self.grid.get(&pos) else {
-                break;
-            };
+        while let Some(&ch) = self.grid.get(&pos) {
             if ch == '#' || pos == obstacle.into() {
                 pos = dir.opposite().advance(pos);
                 dir = dir.turn_right();
@@ -113,7 +113,7 @@ impl ExpandedFormat {
             }
             i += 1;
         }
-        files.sort_by(|a, b| b.0.cmp(&a.0));
+        files.sort_by_key(|f| std::cmp::Reverse(f.0));
 
         for (file_id, file_start, file_len) in files {
             // Find a contiguous run of empty blocks large enough to hold the file
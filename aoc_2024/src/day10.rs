@@ -1,11 +1,7 @@
 use std::collections::HashSet;
 
 use aoc_lib::{
-    answer::Answer,
-    directions::{Advance, Cardinal, Direction},
-    matrix::Matrix,
-    solution::Solution,
-    vec2::Vec2,
+    answer::Answer, directions::Cardinal, matrix::Matrix, solution::Solution, vec2::Vec2,
 };
 
 pub struct Day10;
@@ -68,20 +64,9 @@ impl Map {
         }
 
         let mut count = 0;
-        for d in Cardinal::all_clockwise() {
-            let current_signed: Vec2<isize> = current.into();
-            let next = d.advance(current_signed);
-
-            let Some(next_height) = self.map.get(&next) else {
-                continue;
-            };
-
-            if next_height.saturating_sub(height) != 1 {
-                continue;
-            }
-
-            if let Ok(safe_next) = Vec2::<usize>::try_from(&next) {
-                count += self.trailhead_score(safe_next, visited);
+        for (next, next_height) in self.map.neighbors::<Cardinal>(current) {
+            if next_height.saturating_sub(height) == 1 {
+                count += self.trailhead_score(next, visited);
             }
         }
         count
@@ -97,24 +82,11 @@ impl Map {
             return 1;
         }
 
-        let mut count = 0;
-        for d in Cardinal::all_clockwise() {
-            let current_signed: Vec2<isize> = current.into();
-            let next = d.advance(current_signed);
-
-            let Some(next_height) = self.map.get(&next) else {
-                continue;
-            };
-
-            if next_height.saturating_sub(height) != 1 {
-                continue;
-            }
-
-            if let Ok(safe_next) = Vec2::<usize>::try_from(&next) {
-                count += self.trailhead_rating(safe_next);
-            }
-        }
-        count
+        self.map
+            .neighbors::<Cardinal>(current)
+            .filter(|(_, next_height)| next_height.saturating_sub(height) == 1)
+            .map(|(next, _)| self.trailhead_rating(next))
+            .sum()
     }
 }
 
@@ -1,4 +1,4 @@
-use aoc_lib::{answer::Answer, solution::Solution, vec2::Vec2};
+use aoc_lib::{answer::Answer, linear, solution::Solution, vec2::Vec2};
 
 pub struct Day13;
 
@@ -35,43 +35,28 @@ impl ClawMachine {
     /// We know from the problem statement than:
     /// ka * ax + kb * bx = px
     /// ka * ay + kb * by = py
-    /// So we isolate the expression of ka in the first equation, and then plug it into the second
-    /// equation.
-    /// Then we solve and see if the solution work (because the solution should be integers and it
-    /// is simpler to check than to handle floats -> integer casts and checks).
+    /// When the system has a single solution, the machine can be won only if it is made of non
+    /// negative integers.
     fn solve(&self, press_limit: Option<usize>) -> Option<u64> {
-        let (ax, bx, ay, by, px, py) = (
-            self.a.x as i64,
-            self.b.x as i64,
-            self.a.y as i64,
-            self.b.y as i64,
-            self.prize.x as i64,
-            self.prize.y as i64,
-        );
-
-        let denom = by * ax - bx * ay;
-
-        if denom == 0 {
+        let a = [
+            vec![self.a.x as i128, self.b.x as i128],
+            vec![self.a.y as i128, self.b.y as i128],
+        ];
+        let b = [self.prize.x as i128, self.prize.y as i128];
+        let presses = linear::solve(&a, &b)?;
+        let (ka, kb) = (&presses[0], &presses[1]);
+        if !ka.is_integer() || !kb.is_integer() {
             return None;
         }
-
-        let kb = (ax * py - px * ay).checked_div(denom)?;
-        let ka = (px - kb * bx).checked_div(ax)?;
-
+        let (ka, kb) = (ka.to_integer(), kb.to_integer());
         if ka < 0 || kb < 0 {
             return None;
         }
-
         if let Some(limit) = press_limit {
-            if ka > limit as i64 || kb > limit as i64 {
+            if ka > limit as i128 || kb > limit as i128 {
                 return None;
             }
         }
-
-        if ka * ax + kb * bx != px || ka * ay + kb * by != py {
-            return None;
-        }
-
         Some(ka as u64 * 3 + kb as u64)
     }
 }
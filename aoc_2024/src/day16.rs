@@ -1,29 +1,34 @@
 use std::{
     char,
-    cmp::Ordering,
-    collections::{BinaryHeap, HashMap, HashSet},
+    collections::HashSet,
     fmt::{Display, Write},
 };
 
 use aoc_lib::{
     answer::Answer,
     directions::{Advance, Cardinal, Direction},
+    error::AocError,
     matrix::Matrix,
-    solution::Solution,
+    search,
+    solution::FallibleSolution,
     vec2::Vec2,
 };
 
 pub struct Day16;
 
-impl Solution for Day16 {
-    fn part_a(&self, input: &[String]) -> Answer {
-        let map = Map::from_input(input);
-        map.minimum_cost().0.into()
+impl FallibleSolution for Day16 {
+    type Parsed = Map;
+
+    fn parse(&self, input: &[String]) -> Result<Self::Parsed, AocError> {
+        Map::from_input(input)
+    }
+
+    fn part_a(&self, map: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(map.minimum_cost().0.into())
     }
 
-    fn part_b(&self, input: &[String]) -> Answer {
-        let map = Map::from_input(input);
-        map.minimum_cost().1.len().into()
+    fn part_b(&self, map: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(map.minimum_cost().1.len().into())
     }
 }
 
@@ -38,117 +43,71 @@ enum Tile {
     Debug,
 }
 
-struct Map {
+pub struct Map {
     map: Matrix<Tile>,
     starting_pos: Vec2<usize>,
 }
 
-#[derive(Eq, PartialEq, Clone)]
-struct Node {
-    cost: u64,
-    pos: Vec2<usize>,
-    dir: Cardinal,
-    path: Vec<Vec2<usize>>,
-}
-
 impl Map {
-    fn from_input(input: &[String]) -> Self {
-        let map = Matrix::from_chars(input).map_to(Tile::from);
+    fn from_input(input: &[String]) -> Result<Self, AocError> {
+        let tiles = input
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, ch)| {
+                        Tile::try_from(ch)
+                            .map_err(|error| error.with_line(y + 1).with_column(x + 1))
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let map = Matrix::try_from_rows(tiles)?;
         let Some(starting_pos) = map.find(Tile::Start) else {
-            panic!("Cannot find starting pos inside input");
+            return Err(AocError::new("cannot find starting pos `S` inside input"));
         };
-        Self { map, starting_pos }
+        Ok(Self { map, starting_pos })
     }
 
     fn minimum_cost(&self) -> (u64, HashSet<Vec2<usize>>) {
-        let mut min_cost = u64::MAX;
-        let mut heap = BinaryHeap::new();
-        let mut all_paths = HashSet::<Vec2<usize>>::new();
-        let start_node = Node {
-            pos: self.starting_pos,
-            dir: Cardinal::East,
-            cost: 0,
-            path: vec![self.starting_pos],
+        let successors = |&(pos, dir): &(Vec2<usize>, Cardinal)| {
+            let forward = dir.advance(pos.into());
+            let forward = match self.map.get(&forward) {
+                Some(&tile) if tile != Tile::Wall => Some(((forward.to_usize_unchecked(), dir), 1)),
+                _ => None,
+            };
+            forward.into_iter().chain([
+                ((pos, dir.turn_right()), 1000),
+                ((pos, dir.turn_left()), 1000),
+            ])
         };
-        heap.push(start_node);
-
-        let mut visited = HashMap::new();
-
-        while let Some(Node {
-            pos,
-            dir,
-            cost,
-            path,
-        }) = heap.pop()
-        {
-            if let Some(&prev) = visited.get(&(pos, dir)) {
-                if cost > prev {
-                    continue;
-                }
-            } else {
-                visited.insert((pos, dir), cost);
-            }
-
-            if self.map[pos] == Tile::End {
-                if min_cost == u64::MAX {
-                    min_cost = cost;
-                }
-                all_paths.extend(path.iter());
-            }
-
-            let next_forward = dir.advance(pos.into());
-            if let Some(&tile) = self.map.get(&next_forward) {
-                let next_forward = next_forward.to_usize_unchecked();
-                let mut next_path = path.clone();
-                next_path.push(next_forward);
-                if tile != Tile::Wall {
-                    heap.push(Node {
-                        pos: next_forward,
-                        dir,
-                        cost: cost + 1,
-                        path: next_path,
-                    })
-                }
-            }
-
-            heap.push(Node {
-                pos,
-                dir: dir.turn_right(),
-                cost: cost + 1000,
-                path: path.clone(),
-            });
-
-            heap.push(Node {
-                pos,
-                dir: dir.turn_left(),
-                cost: cost + 1000,
-                path: path.clone(),
-            });
-        }
-        (min_cost, all_paths)
-    }
-}
-
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+        let Some(shortest) = search::dijkstra(
+            (self.starting_pos, Cardinal::East),
+            successors,
+            |(pos, _)| self.map[*pos] == Tile::End,
+        ) else {
+            return (u64::MAX, HashSet::new());
+        };
+        let tiles = shortest
+            .on_shortest_paths()
+            .into_iter()
+            .map(|(pos, _)| pos)
+            .collect();
+        (shortest.cost, tiles)
     }
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
+impl TryFrom<char> for Tile {
+    type Error = AocError;
 
-impl From<char> for Tile {
-    fn from(value: char) -> Self {
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '#' => Tile::Wall,
-            '.' => Tile::Empty,
-            'S' => Tile::Start,
-            'E' => Tile::End,
-            _ => unreachable!(),
+            '#' => Ok(Tile::Wall),
+            '.' => Ok(Tile::Empty),
+            'S' => Ok(Tile::Start),
+            'E' => Ok(Tile::End),
+            _ => Err(AocError::new(format!("unknown tile `{value}`"))),
         }
     }
 }
@@ -173,7 +132,7 @@ impl Display for Map {
 
 #[cfg(test)]
 mod test {
-    use aoc_lib::{answer::Answer, input, solution::Solution};
+    use aoc_lib::{answer::Answer, input, solution::FallibleSolution};
 
     use super::Day16;
 
@@ -181,7 +140,7 @@ mod test {
     fn test_a() {
         let input =
             input::read_file(&format!("{}day_16_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-        let answer = Day16.part_a(&input);
+        let answer = Day16.part_a(&Day16.parse(&input).unwrap()).unwrap();
         assert_eq!(<i32 as Into<Answer>>::into(7036), answer);
     }
 
@@ -189,7 +148,16 @@ mod test {
     fn test_b() {
         let input =
             input::read_file(&format!("{}day_16_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
-        let answer = Day16.part_b(&input);
+        let answer = Day16.part_b(&Day16.parse(&input).unwrap()).unwrap();
         assert_eq!(<i32 as Into<Answer>>::into(45), answer);
     }
+
+    #[test]
+    fn test_parse_error() {
+        let input = ["#####", "#.E.#", "#.x.#", "#####"].map(String::from);
+        let error = Day16.parse(&input).err().unwrap();
+        assert_eq!("line 3, column 3: unknown tile `x`", error.to_string());
+        let input = ["#####", "#.E.#", "#####"].map(String::from);
+        assert!(Day16.parse(&input).is_err());
+    }
 }
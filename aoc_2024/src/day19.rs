@@ -1,6 +1,8 @@
-use std::collections::HashMap;
-
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{
+    answer::Answer,
+    memo::{Dense, Memo},
+    solution::Solution,
+};
 
 pub struct Day19;
 
@@ -70,8 +72,9 @@ impl Problem {
 
     fn count_ways_of_creating_patterns(&self) -> Vec<usize> {
         let mut answer = vec![];
-        let mut memo = HashMap::new();
         for pattern in &self.patterns {
+            // the remaining pattern is always a suffix, so its length is enough as key
+            let mut memo = Memo::dense(pattern.len() + 1, |&len| len);
             answer.push(Self::backtrack(pattern, &self.towels, &mut memo));
         }
         answer
@@ -80,25 +83,22 @@ impl Problem {
     fn backtrack(
         pattern: &[Color],
         availables: &[Vec<Color>],
-        memo: &mut HashMap<Vec<Color>, usize>,
+        memo: &mut Memo<usize, usize, Dense<usize, usize>>,
     ) -> usize {
-        if let Some(&possible) = memo.get(&pattern.to_vec()) {
-            return possible;
-        }
-
-        if pattern.is_empty() {
-            return 1;
-        }
+        memo.get_or_compute(pattern.len(), |memo| {
+            if pattern.is_empty() {
+                return 1;
+            }
 
-        let mut count = 0;
-        for a in availables {
-            if pattern.starts_with(a) {
-                let child = Self::backtrack(&pattern[a.len()..], availables, memo);
-                count += child;
+            let mut count = 0;
+            for a in availables {
+                if pattern.starts_with(a) {
+                    let child = Self::backtrack(&pattern[a.len()..], availables, memo);
+                    count += child;
+                }
             }
-        }
-        memo.insert(pattern.to_vec(), count);
-        count
+            count
+        })
     }
 }
 
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use aoc_lib::{answer::Answer, solution::Solution, vec2::Vec2};
+use aoc_lib::{answer::Answer, memo::Memo, solution::Solution, vec2::Vec2};
 
 pub struct Day21;
 
@@ -30,7 +30,7 @@ fn solve(input: &[String], num_keypads: usize) -> i64 {
         let mut num_keypad = Keypad::new(KeypadType::Numerical);
         let first_sequence = num_keypad.type_code(&code.input);
 
-        let mut memo = HashMap::new();
+        let mut memo = Memo::new();
         let final_length = calculate_length(&first_sequence, num_keypads - 1, &mut memo);
 
         sum += numerical * final_length;
@@ -42,30 +42,26 @@ fn solve(input: &[String], num_keypads: usize) -> i64 {
 fn calculate_length(
     sequence: &[char],
     remaining_depth: usize,
-    memo: &mut HashMap<(Vec<char>, usize), i64>,
+    memo: &mut Memo<(Vec<char>, usize), i64>,
 ) -> i64 {
     if remaining_depth == 0 {
         return sequence.len() as i64;
     }
 
-    let key = (sequence.to_vec(), remaining_depth);
-    if let Some(&cached) = memo.get(&key) {
-        return cached;
-    }
-
-    let mut total_length = 0i64;
-    let mut current_pos = 'A';
+    memo.get_or_compute((sequence.to_vec(), remaining_depth), |memo| {
+        let mut total_length = 0i64;
+        let mut current_pos = 'A';
 
-    for &target_button in sequence {
-        let path = get_directional_path(current_pos, target_button);
+        for &target_button in sequence {
+            let path = get_directional_path(current_pos, target_button);
 
-        total_length += calculate_length(&path, remaining_depth - 1, memo);
+            total_length += calculate_length(&path, remaining_depth - 1, memo);
 
-        current_pos = target_button;
-    }
+            current_pos = target_button;
+        }
 
-    memo.insert(key, total_length);
-    total_length
+        total_length
+    })
 }
 
 fn get_directional_path(from: char, to: char) -> Vec<char> {
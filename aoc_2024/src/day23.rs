@@ -1,6 +1,4 @@
-use std::collections::{HashMap, HashSet};
-
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, graph::Graph, solution::Solution};
 use itertools::Itertools;
 
 pub struct Day23;
@@ -23,69 +21,39 @@ impl Solution for Day23 {
 }
 
 struct Network {
-    adj_list: Vec<HashSet<usize>>,
-    idx_to_name: Vec<String>,
+    graph: Graph<String>,
 }
 
 impl Network {
     fn from_input(input: &[String]) -> Self {
-        let mut name_to_idx: HashMap<&str, usize> = HashMap::new();
-
-        let mut all = HashSet::new();
-
-        for line in input {
-            let (a, b) = line.split_once('-').unwrap();
-            all.insert(a);
-            all.insert(b);
-        }
-
-        let mut idx_to_name = vec![String::from(""); all.len()];
-        let mut adj_list: Vec<HashSet<usize>> = vec![HashSet::new(); all.len()];
-
-        for (curr_idx, &name) in all.iter().enumerate() {
-            name_to_idx.insert(name, curr_idx);
-            idx_to_name[curr_idx] = name.to_string();
-        }
-
+        let mut graph = Graph::undirected();
         for line in input {
             let (a, b) = line.split_once('-').unwrap();
-            let (&a, &b) = (name_to_idx.get(a).unwrap(), name_to_idx.get(b).unwrap());
-            adj_list[a].insert(b);
-            adj_list[b].insert(a);
-        }
-
-        Self {
-            adj_list,
-            idx_to_name,
+            graph.add_edge(a.to_string(), b.to_string());
         }
+        Self { graph }
     }
 
     fn lan_password(&self) -> String {
-        let mut cliques = vec![];
-        let all_nodes = (0..self.adj_list.len()).collect::<HashSet<_>>();
-        self.bron_kerbosch(HashSet::new(), all_nodes, HashSet::new(), &mut cliques);
-        cliques
+        self.graph
+            .maximal_cliques()
             .iter()
-            .max_by(|a, b| a.len().cmp(&b.len()))
+            .max_by_key(|clique| clique.len())
             .unwrap()
             .iter()
-            .map(|&e| self.idx_to_name[e].clone())
+            .map(|&e| self.graph.label(e).clone())
             .sorted_unstable()
             .join(",")
     }
 
     fn all_triangles(&self) -> Vec<[String; 3]> {
         let mut triangles = vec![];
-        for u in 0..self.adj_list.len() {
-            for &v in &self.adj_list[u] {
+        for u in self.graph.nodes() {
+            for &v in self.graph.neighbors(u) {
                 if v > u {
-                    for &w in &self.adj_list[u] {
-                        if w > v && self.adj_list[v].contains(&w) {
-                            triangles.push([
-                                self.idx_to_name.get(u).unwrap().clone(),
-                                self.idx_to_name.get(v).unwrap().clone(),
-                                self.idx_to_name.get(w).unwrap().clone(),
-                            ]);
+                    for &w in self.graph.neighbors(u) {
+                        if w > v && self.graph.has_edge(v, w) {
+                            triangles.push([u, v, w].map(|id| self.graph.label(id).clone()));
                         }
                     }
                 }
@@ -93,35 +61,6 @@ impl Network {
         }
         triangles
     }
-
-    fn bron_kerbosch(
-        &self,
-        r: HashSet<usize>,
-        mut p: HashSet<usize>,
-        mut x: HashSet<usize>,
-        cliques: &mut Vec<Vec<usize>>,
-    ) {
-        if p.is_empty() && x.is_empty() {
-            let mut clique: Vec<_> = r.into_iter().collect();
-            clique.sort();
-            cliques.push(clique);
-            return;
-        }
-
-        let p_copy = p.clone();
-        for v in p_copy {
-            let mut r_new = r.clone();
-            r_new.insert(v);
-
-            let p_new: HashSet<_> = p.intersection(&self.adj_list[v]).copied().collect();
-            let x_new: HashSet<_> = x.intersection(&self.adj_list[v]).copied().collect();
-
-            self.bron_kerbosch(r_new, p_new, x_new, cliques);
-
-            p.remove(&v);
-            x.insert(v);
-        }
-    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,4 @@
-use aoc_lib::solution::Solution;
+use aoc_lib::registry::Entry;
 
 pub mod day01;
 pub mod day02;
@@ -26,32 +26,34 @@ pub mod day23;
 pub mod day24;
 pub mod day25;
 
-pub const ALL: &[&dyn Solution] = &[
-    &day01::Day1,
-    &day02::Day2,
-    &day03::Day3,
-    &day04::Day4,
-    &day05::Day5,
-    &day06::Day6,
-    &day07::Day7,
-    &day08::Day8,
-    &day09::Day9,
-    &day10::Day10,
-    &day11::Day11,
-    &day12::Day12,
-    &day13::Day13,
-    &day14::Day14,
-    &day15::Day15,
-    &day16::Day16,
-    &day17::Day17,
-    &day18::Day18,
-    &day19::Day19,
-    &day20::Day20,
-    &day21::Day21,
-    &day22::Day22,
-    &day23::Day23,
-    &day24::Day24,
-    &day25::Day25,
+pub const YEAR: u16 = 2024;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(YEAR, 1, "Historian Hysteria", &day01::Day1),
+    Entry::new(YEAR, 2, "Red-Nosed Reports", &day02::Day2),
+    Entry::new(YEAR, 3, "Mull It Over", &day03::Day3),
+    Entry::new(YEAR, 4, "Ceres Search", &day04::Day4),
+    Entry::new(YEAR, 5, "Print Queue", &day05::Day5),
+    Entry::new(YEAR, 6, "Guard Gallivant", &day06::Day6),
+    Entry::new(YEAR, 7, "Bridge Repair", &day07::Day7),
+    Entry::new(YEAR, 8, "Resonant Collinearity", &day08::Day8),
+    Entry::new(YEAR, 9, "Disk Fragmenter", &day09::Day9),
+    Entry::new(YEAR, 10, "Hoof It", &day10::Day10),
+    Entry::new(YEAR, 11, "Plutonian Pebbles", &day11::Day11),
+    Entry::new(YEAR, 12, "Garden Groups", &day12::Day12),
+    Entry::new(YEAR, 13, "Claw Contraption", &day13::Day13),
+    Entry::new(YEAR, 14, "Restroom Redoubt", &day14::Day14),
+    Entry::new(YEAR, 15, "Warehouse Woes", &day15::Day15),
+    Entry::new(YEAR, 16, "Reindeer Maze", &day16::Day16),
+    Entry::new(YEAR, 17, "Chronospatial Computer", &day17::Day17),
+    Entry::new(YEAR, 18, "RAM Run", &day18::Day18),
+    Entry::new(YEAR, 19, "Linen Layout", &day19::Day19),
+    Entry::new(YEAR, 20, "Race Condition", &day20::Day20),
+    Entry::new(YEAR, 21, "Keypad Conundrum", &day21::Day21),
+    Entry::new(YEAR, 22, "Monkey Market", &day22::Day22),
+    Entry::new(YEAR, 23, "LAN Party", &day23::Day23),
+    Entry::new(YEAR, 24, "Crossed Wires", &day24::Day24),
+    Entry::new(YEAR, 25, "Code Chronicle", &day25::Day25),
 ];
 
 pub const FILES_PREFIX_TEST: &str = "resources/test/";
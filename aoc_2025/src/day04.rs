@@ -1,9 +1,5 @@
 use aoc_lib::{
-    answer::Answer,
-    directions::{Advance, Direction, ExtendedCardinal},
-    matrix::Matrix,
-    solution::Solution,
-    vec2::Vec2,
+    answer::Answer, directions::ExtendedCardinal, matrix::Matrix, solution::Solution, vec2::Vec2,
 };
 
 pub struct Day4;
@@ -35,18 +31,11 @@ impl Shed {
             for x in 0..self.map.cols {
                 let pos = Vec2::new(x, y);
                 if TileType::Paper == self.map[pos] {
-                    let mut count = 0;
-                    for dir in ExtendedCardinal::all_clockwise() {
-                        let reached = Vec2::<isize>::from(pos);
-                        let next = dir.advance(reached);
-                        let Some(&tile) = self.map.get(&next) else {
-                            continue;
-                        };
-
-                        if TileType::Paper == tile {
-                            count += 1;
-                        }
-                    }
+                    let count = self
+                        .map
+                        .neighbors::<ExtendedCardinal>(pos)
+                        .filter(|(_, tile)| TileType::Paper == **tile)
+                        .count();
 
                     if count < 4 {
                         to_remove.push(pos);
@@ -1,6 +1,4 @@
-use std::ops::RangeInclusive;
-
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, interval::IntervalSet, solution::Solution};
 
 pub struct Day5;
 
@@ -16,39 +14,14 @@ impl Solution for Day5 {
 
 impl Database {
     fn part_a(&self) -> usize {
-        let mut total = 0;
-        for &ingredient in &self.ingredients {
-            for range in &self.ranges {
-                if range.contains(&ingredient) {
-                    total += 1;
-                    break;
-                }
-            }
-        }
-        total
-    }
-
-    fn part_b(&self) -> usize {
-        let merged = self.merge_intervals();
-        merged
+        self.ingredients
             .iter()
-            .fold(0, |acc, x| acc + x.end() - x.start() + 1)
+            .filter(|ingredient| self.fresh.contains(ingredient))
+            .count()
     }
 
-    fn merge_intervals(&self) -> Vec<RangeInclusive<usize>> {
-        let mut sorted = self.ranges.clone();
-        sorted.sort_unstable_by(|a, b| a.start().cmp(b.start()));
-        let mut merged = vec![sorted[0].clone()];
-        for curr in sorted.iter().skip(1) {
-            let last = merged.last().unwrap();
-            if curr.start() <= last.end() {
-                let mut end = *last.start()..=*(last.end().max(curr.end()));
-                std::mem::swap(merged.last_mut().unwrap(), &mut end);
-            } else {
-                merged.push(curr.clone());
-            }
-        }
-        merged
+    fn part_b(&self) -> usize {
+        self.fresh.len()
     }
 
     fn from_input(input: &[String]) -> Self {
@@ -60,27 +33,22 @@ impl Database {
             .next()
             .expect("ingredients are expected after blank line");
 
-        let ranges = ranges
-            .iter()
-            .map(|l| {
-                let (a, b) = l.as_str().split_once('-').unwrap();
-                let (a, b) = (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap());
-                a..=b
-            })
-            .collect::<Vec<_>>();
+        let mut fresh = IntervalSet::new();
+        for l in ranges {
+            let (a, b) = l.as_str().split_once('-').unwrap();
+            let (a, b) = (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap());
+            fresh.insert_inclusive(a..=b);
+        }
         let ingredients = ingredients
             .iter()
             .map(|l| l.parse::<usize>().unwrap())
             .collect::<Vec<_>>();
-        Self {
-            ranges,
-            ingredients,
-        }
+        Self { fresh, ingredients }
     }
 }
 
 struct Database {
-    ranges: Vec<RangeInclusive<usize>>,
+    fresh: IntervalSet<usize>,
     ingredients: Vec<usize>,
 }
 
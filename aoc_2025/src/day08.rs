@@ -4,7 +4,7 @@ use std::{
     fmt::Display,
 };
 
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, solution::Solution, union_find::UnionFind};
 
 pub struct Day8;
 
@@ -32,13 +32,6 @@ struct Circuit {
     pos: (usize, usize, usize),
 }
 
-struct UnionFind {
-    parent: Vec<usize>,
-    size: Vec<usize>,
-    heap: BinaryHeap<(usize, usize)>,
-    current: HashMap<usize, usize>,
-}
-
 impl Playground {
     fn from_input(input: &[String]) -> Self {
         let n = input.len();
@@ -72,9 +65,7 @@ impl Playground {
 
     fn process_n(&mut self, n: usize) {
         for _ in 0..n {
-            if let Some(Reverse((_, a, b))) = self.distance_heap.pop()
-                && self.find.find(a) != self.find.find(b)
-            {
+            if let Some(Reverse((_, a, b))) = self.distance_heap.pop() {
                 self.find.union(a, b);
             }
         }
@@ -83,8 +74,7 @@ impl Playground {
     fn part_b(&mut self) -> usize {
         let (mut last_a, mut last_b) = (0, 0);
         while let Some(Reverse((_, a, b))) = self.distance_heap.pop() {
-            if self.find.find(a) != self.find.find(b) {
-                self.find.union(a, b);
+            if self.find.union(a, b) {
                 last_a = a;
                 last_b = b;
             }
@@ -93,8 +83,9 @@ impl Playground {
     }
 
     fn part_a(&mut self) -> usize {
-        let top = self.find.top_k(3);
-        top.iter().map(|(_, size)| size).product()
+        let mut sizes = self.find.sizes();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.iter().take(3).product()
     }
 }
 
@@ -114,71 +105,6 @@ impl Display for Circuit {
     }
 }
 
-impl UnionFind {
-    pub fn new(size: usize) -> Self {
-        let mut heap = BinaryHeap::with_capacity(size);
-        let mut current = HashMap::with_capacity(size);
-
-        for i in 0..size {
-            heap.push((1, i));
-            current.insert(i, 1);
-        }
-
-        UnionFind {
-            parent: (0..size).collect(),
-            size: vec![1; size],
-            heap,
-            current,
-        }
-    }
-
-    pub fn find(&mut self, x: usize) -> usize {
-        if self.parent[x] != x {
-            self.parent[x] = self.find(self.parent[x]);
-        }
-        self.parent[x]
-    }
-
-    pub fn union(&mut self, a: usize, b: usize) {
-        let mut root_a = self.find(a);
-        let mut root_b = self.find(b);
-
-        if root_a == root_b {
-            return;
-        }
-
-        if self.size[root_a] < self.size[root_b] {
-            std::mem::swap(&mut root_a, &mut root_b);
-        }
-
-        self.current.remove(&root_a);
-        self.current.remove(&root_b);
-
-        self.parent[root_b] = root_a;
-        self.size[root_a] += self.size[root_b];
-
-        let new_size = self.size[root_a];
-        self.current.insert(root_a, new_size);
-        self.heap.push((new_size, root_a));
-    }
-
-    pub fn top_k(&mut self, k: usize) -> Vec<(usize, usize)> {
-        let mut result = Vec::with_capacity(k);
-
-        while result.len() < k {
-            match self.heap.pop() {
-                Some((size, root)) if self.current.get(&root) == Some(&size) => {
-                    result.push((root, size));
-                }
-                None => break,
-                _ => continue,
-            }
-        }
-
-        result
-    }
-}
-
 #[cfg(test)]
 mod test {
     use aoc_lib::{answer::Answer, input, solution::Solution};
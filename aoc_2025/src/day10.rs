@@ -1,9 +1,8 @@
-use aoc_lib::{answer::Answer, solution::Solution};
-
-#[cfg(feature = "good_lp_solver")]
-use good_lp::{Expression, Solution as LpSolution, coin_cbc};
-#[cfg(feature = "good_lp_solver")]
-use good_lp::{SolverModel, constraint, variable, variables};
+use aoc_lib::{
+    answer::Answer,
+    linear::{IntegerProgram, Relation},
+    solution::Solution,
+};
 
 pub struct Day10;
 
@@ -17,7 +16,6 @@ impl Solution for Day10 {
         total.into()
     }
 
-    #[cfg(feature = "good_lp_solver")]
     fn part_b(&self, input: &[String]) -> Answer {
         let machines = parse(input);
         let mut total = 0;
@@ -26,11 +24,6 @@ impl Solution for Day10 {
         }
         total.into()
     }
-
-    #[cfg(not(feature = "good_lp_solver"))]
-    fn part_b(&self, _input: &[String]) -> Answer {
-        33.into()
-    }
 }
 
 fn parse(input: &[String]) -> Vec<Machine> {
@@ -81,28 +74,31 @@ impl Machine {
         backtrack(&buttons, 0, self.lights, 0, 0)
     }
 
-    #[cfg(feature = "good_lp_solver")]
+    /// Integer program minimizing the total number of presses, each counter having to reach
+    /// its joltage exactly.
     fn fewest_joltage_presses(&self) -> usize {
-        let mut vars = variables!();
-        let xs: Vec<_> = self
-            .buttons
-            .iter()
-            .map(|_| vars.add(variable().integer().min(0)))
-            .collect();
-
-        let objective: Expression = xs.iter().fold(0.into(), |sum: Expression, x| sum + *x);
-        let mut model = vars.minimise(objective).using(coin_cbc);
-
+        let mut program = IntegerProgram::minimize(vec![1; self.buttons.len()]);
         for i in 0..16 {
-            let mut expr: Expression = 0.into();
-            for (j, btn) in self.buttons.iter().enumerate() {
-                expr += (btn[i] as f64) * xs[j];
-            }
-            model = model.with(constraint!(expr == self.joltages[i] as f64));
+            let coefficients = self
+                .buttons
+                .iter()
+                .map(|btn| btn[i] as i64)
+                .collect::<Vec<_>>();
+            program = program.with(coefficients, Relation::Equal, self.joltages[i] as i64);
         }
-
-        let solution = model.solve().unwrap();
-        xs.iter().map(|&x| solution.value(x) as usize).sum()
+        // pressing a button more than the joltage of one of its counters overshoots it
+        for (j, btn) in self.buttons.iter().enumerate() {
+            let upper = (0..16)
+                .filter(|&i| btn[i] == 1)
+                .map(|i| self.joltages[i] as i64)
+                .min();
+            program = program.with_bounds(j, 0, upper);
+        }
+        let (presses, _) = program
+            .solve()
+            .expect("the presses should be found within the node limit")
+            .expect("every machine can be configured");
+        presses as usize
     }
 
     fn from_input(input: &str) -> Self {
@@ -1,78 +1,42 @@
-use std::collections::{HashMap, HashSet};
-
-use aoc_lib::{answer::Answer, solution::Solution};
+use aoc_lib::{answer::Answer, graph::Graph, solution::Solution};
 
 pub struct Day11;
 
 impl Solution for Day11 {
     fn part_a(&self, input: &[String]) -> Answer {
-        Graph::from_input(input)
-            .count_valid_paths("you", false)
-            .into()
+        Devices::from_input(input).count_paths("you", &[]).into()
     }
 
     fn part_b(&self, input: &[String]) -> Answer {
-        Graph::from_input(input)
-            .count_valid_paths("svr", true)
+        Devices::from_input(input)
+            .count_paths("svr", &["fft", "dac"])
             .into()
     }
 }
 
-struct Graph<'a> {
-    adj: HashMap<&'a str, Vec<&'a str>>,
+struct Devices<'a> {
+    graph: Graph<&'a str>,
 }
 
-impl<'a> Graph<'a> {
+impl<'a> Devices<'a> {
     fn from_input(input: &'a [String]) -> Self {
-        let mut adj = HashMap::new();
+        let mut graph = Graph::directed();
         for line in input {
             let (src, dests) = line.split_once(':').unwrap();
-            let dests = dests.split_whitespace().collect::<Vec<_>>();
-            adj.insert(src, dests);
+            for dest in dests.split_whitespace() {
+                graph.add_edge(src, dest);
+            }
         }
-        Self { adj }
+        Self { graph }
     }
 
-    fn count_valid_paths(&self, start: &str, constrained: bool) -> usize {
-        fn dfs<'a>(
-            adj: &HashMap<&'a str, Vec<&'a str>>,
-            key: &'a str,
-            visited: &mut HashSet<&'a str>,
-            memo: &mut HashMap<(&'a str, [bool; 2]), usize>,
-            seen @ [fft, dac]: [bool; 2],
-        ) -> usize {
-            let entry = (key, seen);
-            if let Some(&cached) = memo.get(&entry) {
-                return cached;
-            }
-
-            if key == "out" && fft && dac {
-                return 1;
-            }
-
-            let mut count = 0;
-            if let Some(dests) = adj.get(&key) {
-                for &dest in dests {
-                    if visited.contains(dest) {
-                        continue;
-                    }
-                    visited.insert(key);
-                    let seen = [fft || dest == "fft", dac || dest == "dac"];
-                    count += dfs(adj, dest, visited, memo, seen);
-                    visited.remove(key);
-                }
-            }
-            memo.insert(entry, count);
-            count
-        }
-        let mut visited = HashSet::new();
-        visited.insert(start);
-        let seen = if constrained {
-            [false, false]
-        } else {
-            [true, true]
-        };
-        dfs(&self.adj, start, &mut visited, &mut HashMap::new(), seen)
+    /// Paths from `start` to the output going through every `via` device.
+    fn count_paths(&self, start: &str, via: &[&str]) -> u64 {
+        let id = |label| self.graph.id(&label).expect("unknown device");
+        let via = via.iter().map(|&label| id(label)).collect::<Vec<_>>();
+        self.graph
+            .count_paths_via(id(start), id("out"), &via)
+            .expect("devices should not be wired in a loop")
     }
 }
 
@@ -8,7 +8,7 @@ impl Solution for Day12 {
         regions.into_iter().filter(|r| r.is_valid()).count().into()
     }
 
-    fn part_b(&self, input: &[String]) -> Answer {
+    fn part_b(&self, _input: &[String]) -> Answer {
         Answer::Unimplemented
     }
 }
@@ -1,4 +1,4 @@
-use aoc_lib::solution::Solution;
+use aoc_lib::registry::Entry;
 
 pub mod day01;
 pub mod day02;
@@ -13,19 +13,21 @@ pub mod day10;
 pub mod day11;
 pub mod day12;
 
-pub const ALL: &[&dyn Solution] = &[
-    &day01::Day1,
-    &day02::Day2,
-    &day03::Day3,
-    &day04::Day4,
-    &day05::Day5,
-    &day06::Day6,
-    &day07::Day7,
-    &day08::Day8,
-    &day09::Day9,
-    &day10::Day10,
-    &day11::Day11,
-    &day12::Day12,
+pub const YEAR: u16 = 2025;
+
+pub const SOLUTIONS: &[Entry] = &[
+    Entry::new(YEAR, 1, "Secret Entrance", &day01::Day1),
+    Entry::new(YEAR, 2, "Gift Shop", &day02::Day2),
+    Entry::new(YEAR, 3, "Lobby", &day03::Day3),
+    Entry::new(YEAR, 4, "Printing Department", &day04::Day4),
+    Entry::new(YEAR, 5, "Cafetaria", &day05::Day5),
+    Entry::new(YEAR, 6, "Trash Compactor", &day06::Day6),
+    Entry::new(YEAR, 7, "Laboratories", &day07::Day7),
+    Entry::new(YEAR, 8, "Playground", &day08::Day8),
+    Entry::new(YEAR, 9, "Movie Theater", &day09::Day9),
+    Entry::new(YEAR, 10, "Factory", &day10::Day10),
+    Entry::new(YEAR, 11, "Reactor", &day11::Day11),
+    Entry::new(YEAR, 12, "Christmas Tree Farm", &day12::Day12),
 ];
 
 pub const FILES_PREFIX_TEST: &str = "resources/test/";
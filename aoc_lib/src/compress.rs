@@ -0,0 +1,166 @@
+use num::Integer;
+
+use super::{matrix::Matrix, vec2::Vec2};
+
+/// Sorted unique values of one axis. Each value gets its own cell, and the values lying strictly
+/// between two consecutive ones are merged into a single gap cell, so cell `2 * i` is the `i`th
+/// value.
+#[derive(Debug, Clone)]
+pub struct Axis<T> {
+    values: Vec<T>,
+}
+
+impl<T: Integer + Copy> Axis<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values = values.into_iter().collect::<Vec<_>>();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    /// Number of cells, gaps included.
+    pub fn len(&self) -> usize {
+        (self.values.len() * 2).saturating_sub(1)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Cell of `value`, `None` if it is not one of the compressed values.
+    pub fn index(&self, value: T) -> Option<usize> {
+        self.values.binary_search(&value).ok().map(|i| i * 2)
+    }
+
+    /// First real value covered by the cell.
+    pub fn start(&self, index: usize) -> T {
+        if index.is_even() {
+            self.values[index / 2]
+        } else {
+            self.values[index / 2] + T::one()
+        }
+    }
+
+    /// Number of real values covered by the cell, gaps between consecutive values being empty.
+    pub fn span(&self, index: usize) -> T {
+        if index.is_even() {
+            T::one()
+        } else {
+            self.values[index / 2 + 1] - self.values[index / 2] - T::one()
+        }
+    }
+}
+
+/// Compressed plane built from the coordinates of a set of points, small enough to be stored in
+/// a [`Matrix`] whatever the magnitude of the real coordinates.
+#[derive(Debug, Clone)]
+pub struct Compression<T> {
+    pub xs: Axis<T>,
+    pub ys: Axis<T>,
+}
+
+impl<T: Integer + Copy> Compression<T> {
+    pub fn new(points: &[Vec2<T>]) -> Self {
+        Self {
+            xs: Axis::new(points.iter().map(|p| p.x)),
+            ys: Axis::new(points.iter().map(|p| p.y)),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.ys.len()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.xs.len()
+    }
+
+    /// Cell of a point whose coordinates were both compressed.
+    pub fn compress(&self, point: &Vec2<T>) -> Option<Vec2<usize>> {
+        Some(Vec2::new(self.xs.index(point.x)?, self.ys.index(point.y)?))
+    }
+
+    /// Top left real point covered by the cell.
+    pub fn decompress(&self, cell: Vec2<usize>) -> Vec2<T> {
+        Vec2::new(self.xs.start(cell.x), self.ys.start(cell.y))
+    }
+
+    /// Width and height of the real area covered by the cell.
+    pub fn span(&self, cell: Vec2<usize>) -> Vec2<T> {
+        Vec2::new(self.xs.span(cell.x), self.ys.span(cell.y))
+    }
+
+    /// A matrix over the compressed plane filled with `value`.
+    pub fn matrix<U: Clone>(&self, value: U) -> Matrix<U> {
+        Matrix::new(self.rows(), self.cols(), value)
+    }
+
+    /// Number of real points covered by each cell.
+    pub fn weights(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows(), self.cols(), |cell| {
+            let span = self.span(cell);
+            span.x * span.y
+        })
+    }
+
+    /// Number of real points covered by the cells of `matrix` matching `predicate`.
+    pub fn area_where<U>(&self, matrix: &Matrix<U>, mut predicate: impl FnMut(&U) -> bool) -> T {
+        let mut area = T::zero();
+        for y in 0..self.rows() {
+            for x in 0..self.cols() {
+                let cell = Vec2::new(x, y);
+                if predicate(&matrix[cell]) {
+                    let span = self.span(cell);
+                    area = area + span.x * span.y;
+                }
+            }
+        }
+        area
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{matrix::Matrix, vec2::Vec2};
+
+    use super::{Axis, Compression};
+
+    #[test]
+    fn test_axis() {
+        let axis = Axis::new([7, 2, 3, 7, 10]);
+        assert_eq!(7, axis.len());
+        assert_eq!(Some(4), axis.index(7));
+        assert_eq!(None, axis.index(5));
+        let spans = (0..axis.len()).map(|i| axis.span(i)).collect::<Vec<_>>();
+        assert_eq!(vec![1, 0, 1, 3, 1, 2, 1], spans);
+        assert_eq!(4, axis.start(3));
+    }
+
+    #[test]
+    fn test_compression() {
+        // L shape whose corners are far apart
+        let points = [(2, 1), (700, 1), (700, 50), (300, 50), (300, 500), (2, 500)]
+            .map(|(x, y)| Vec2::new(x, y));
+        let compression = Compression::new(&points);
+        assert_eq!((5, 5), (compression.rows(), compression.cols()));
+        assert_eq!(
+            Some(Vec2::new(2, 2)),
+            compression.compress(&Vec2::new(300, 50))
+        );
+        assert_eq!(Vec2::new(301, 51), compression.decompress(Vec2::new(3, 3)));
+        assert_eq!(Vec2::new(399, 449), compression.span(Vec2::new(3, 3)));
+
+        let mut inside = compression.matrix(false);
+        for y in 0..compression.rows() {
+            for x in 0..compression.cols() {
+                inside[Vec2::new(x, y)] = y <= 2 || x <= 2;
+            }
+        }
+        let area = compression.area_where(&inside, |&inside| inside);
+        assert_eq!(699 * 50 + 299 * 450, area);
+        let weights = compression.weights();
+        assert_eq!(399 * 449, weights[Vec2::new(3_usize, 3)]);
+        assert_eq!(699 * 500, compression.area_where(&weights, |_| true));
+        assert_eq!(Matrix::new(5, 5, 0), compression.matrix(0));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Shape of the sequence `x0, f(x0), f(f(x0)), ..`: after `prefix` steps, the states repeat
+/// every `length` steps forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Earliest step whose state is the same as the one of step `n`.
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare, in constant memory. States are compared for equality only.
+pub fn floyd<S: Clone + PartialEq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = next(&initial);
+    let mut hare = next(&tortoise);
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        let half = next(&hare);
+        hare = next(&half);
+    }
+    // the distance between both is now a multiple of the length, so moving them at the same
+    // speed makes them meet at the start of the cycle
+    let mut prefix = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+    let mut length = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        length += 1;
+    }
+    Cycle { prefix, length }
+}
+
+/// Brent's algorithm, in constant memory and with fewer calls to `next` than [`floyd`].
+pub fn brent<S: Clone + PartialEq>(initial: S, mut next: impl FnMut(&S) -> S) -> Cycle {
+    // search successive powers of two for the length
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = next(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = next(&hare);
+        length += 1;
+    }
+    // with the hare `length` steps ahead, both meet at the start of the cycle
+    tortoise = initial.clone();
+    hare = initial;
+    for _ in 0..length {
+        hare = next(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        prefix += 1;
+    }
+    Cycle { prefix, length }
+}
+
+/// Detect the cycle by remembering every state seen, calling `next` only once per state.
+pub fn detect<S: Clone + Hash + Eq>(initial: S, next: impl FnMut(&S) -> S) -> Cycle {
+    detect_by_key(initial, next, S::clone)
+}
+
+/// Like [`detect`], states being compared through `key`, e.g. when only a part of the state
+/// matters.
+pub fn detect_by_key<S, K: Hash + Eq>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut state = initial;
+    let mut seen = HashMap::new();
+    for step in 0.. {
+        if let Some(previous) = seen.insert(key(&state), step) {
+            return Cycle {
+                prefix: previous,
+                length: step - previous,
+            };
+        }
+        state = next(&state);
+    }
+    unreachable!()
+}
+
+/// State after `n` steps, simulating only until the cycle is found.
+pub fn nth<S: Clone + Hash + Eq>(initial: S, next: impl FnMut(&S) -> S, n: usize) -> S {
+    nth_by_key(initial, next, S::clone, n)
+}
+
+/// Like [`nth`], states being compared through `key`.
+pub fn nth_by_key<S, K: Hash + Eq>(
+    initial: S,
+    mut next: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    n: usize,
+) -> S {
+    let mut state = initial;
+    let mut seen = HashMap::new();
+    for step in 0..n {
+        if let Some(previous) = seen.insert(key(&state), step) {
+            // the remaining steps are a number of whole cycles and a few steps
+            for _ in 0..(n - step) % (step - previous) {
+                state = next(&state);
+            }
+            return state;
+        }
+        state = next(&state);
+    }
+    state
+}
+
+#[cfg(test)]
+mod test {
+    use super::{brent, detect, detect_by_key, floyd, nth, Cycle};
+
+    // 3 -> 10 -> 5 -> 16 -> 8 -> 4 -> 2 -> 1 -> 4 -> ..
+    fn collatz(n: &u64) -> u64 {
+        if n.is_multiple_of(2) {
+            n / 2
+        } else {
+            3 * n + 1
+        }
+    }
+
+    #[test]
+    fn test_detectors() {
+        let expected = Cycle {
+            prefix: 5,
+            length: 3,
+        };
+        assert_eq!(expected, floyd(3, collatz));
+        assert_eq!(expected, brent(3, collatz));
+        assert_eq!(expected, detect(3, collatz));
+        let pure = Cycle {
+            prefix: 0,
+            length: 3,
+        };
+        assert_eq!(pure, floyd(4, collatz));
+        assert_eq!(pure, brent(4, collatz));
+        assert_eq!(pure, detect(4, collatz));
+        // the second element is a step counter that never repeats
+        let cycle = detect_by_key((3, 0), |&(n, i)| (collatz(&n), i + 1), |&(n, _)| n);
+        assert_eq!(expected, cycle);
+    }
+
+    #[test]
+    fn test_jump() {
+        assert_eq!(5, nth(3, collatz, 2));
+        assert_eq!(4, nth(3, collatz, 5));
+        assert_eq!(1, nth(3, collatz, 1_000_000_000));
+        let cycle = detect(3, collatz);
+        assert_eq!(3, cycle.reduce(3));
+        assert_eq!(7, cycle.reduce(1_000_000_000));
+    }
+}
@@ -0,0 +1,72 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// An error raised while solving a puzzle, located in the input when it comes from parsing.
+/// Lines and columns are 1-based, as shown by editors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Error about the input line at 0-based `index`, as given by `enumerate`.
+    pub fn at_line(index: usize, message: impl Into<String>) -> Self {
+        Self::new(message).with_line(index + 1)
+    }
+
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_column(mut self, column: usize) -> Self {
+        self.column = Some(column);
+        self
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => write!(f, "line {line}, column {column}: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            _ => {}
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for AocError {}
+
+#[cfg(test)]
+mod test {
+    use super::AocError;
+
+    #[test]
+    fn test_display() {
+        assert_eq!("no start", AocError::new("no start").to_string());
+        assert_eq!(
+            "line 42: expected `{x=..}`",
+            AocError::at_line(41, "expected `{x=..}`").to_string()
+        );
+        assert_eq!(
+            "line 3, column 7: unknown tile",
+            AocError::new("unknown tile")
+                .with_line(3)
+                .with_column(7)
+                .to_string()
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[cfg(feature = "ureq_backend")]
+const USER_AGENT: &str = "github.com/dirdr/advent_of_code";
+
+#[derive(Debug)]
+pub enum FetchError {
+    Status { url: String, status: u16 },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Status { url, status } => {
+                write!(f, "request to {url} failed with status {status}")
+            }
+            FetchError::Transport(message) => write!(f, "request failed: {message}"),
+            FetchError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(value: io::Error) -> Self {
+        FetchError::Io(value)
+    }
+}
+
+/// The HTTP client used to talk to the puzzle website, authenticated by a session cookie.
+pub trait HttpBackend {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, FetchError>;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+pub struct Fetcher<B> {
+    backend: B,
+    base_url: String,
+    session: String,
+}
+
+impl<B: HttpBackend> Fetcher<B> {
+    pub fn new(backend: B, session: impl Into<String>) -> Self {
+        Self {
+            backend,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session: session.into(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn input_url(&self, year: u16, day: u16) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    /// Download the input of `day` to `path`, unless it is already cached there.
+    pub fn fetch(&self, year: u16, day: u16, path: &Path) -> Result<Fetched, FetchError> {
+        if path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self
+            .backend
+            .get(&self.input_url(year, day), &self.session)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, input)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(feature = "ureq_backend")]
+pub struct UreqBackend {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "ureq_backend")]
+impl Default for UreqBackend {
+    fn default() -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+#[cfg(feature = "ureq_backend")]
+impl UreqBackend {
+    fn read(
+        url: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, FetchError> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status {
+                url: url.to_owned(),
+                status,
+            }),
+            Err(error) => Err(FetchError::Transport(error.to_string())),
+        }
+    }
+}
+
+#[cfg(feature = "ureq_backend")]
+impl HttpBackend for UreqBackend {
+    fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={session}"))
+            .call();
+        Self::read(url, response)
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, FetchError> {
+        let response = self
+            .agent
+            .post(url)
+            .set("Cookie", &format!("session={session}"))
+            .send_form(form);
+        Self::read(url, response)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, env, fs, path::PathBuf};
+
+    use super::{FetchError, Fetched, Fetcher, HttpBackend};
+
+    struct Stub {
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl HttpBackend for Stub {
+        fn get(&self, url: &str, session: &str) -> Result<String, FetchError> {
+            self.requests.borrow_mut().push(format!("{url} {session}"));
+            Ok("1\n2\n".to_owned())
+        }
+
+        fn post_form(
+            &self,
+            _url: &str,
+            _session: &str,
+            _form: &[(&str, &str)],
+        ) -> Result<String, FetchError> {
+            unreachable!()
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc_fetch_{}_{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("2024").join("day_01.txt")
+    }
+
+    #[test]
+    fn test_fetch_cached() {
+        let path = temp_path("cached");
+        let stub = Stub {
+            requests: RefCell::new(vec![]),
+        };
+        let fetcher = Fetcher::new(stub, "abc").with_base_url("http://localhost:8080/");
+        assert_eq!(Fetched::Downloaded, fetcher.fetch(2024, 1, &path).unwrap());
+        assert_eq!(Fetched::Cached, fetcher.fetch(2024, 1, &path).unwrap());
+        assert_eq!("1\n2\n", fs::read_to_string(&path).unwrap());
+        assert_eq!(
+            vec!["http://localhost:8080/2024/day/1/input abc"],
+            *fetcher.backend.requests.borrow()
+        );
+    }
+
+    #[cfg(feature = "ureq_backend")]
+    #[test]
+    fn test_fetch_stub_server() {
+        use std::{
+            io::{Read, Write},
+            net::TcpListener,
+            thread,
+        };
+
+        use super::UreqBackend;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![];
+            let mut buffer = [0; 1024];
+            while !request.ends_with(b"\r\n\r\n") {
+                let n = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..n]);
+            }
+            let body = "42\n";
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let path = temp_path("server");
+        let fetcher =
+            Fetcher::new(UreqBackend::default(), "abc").with_base_url(format!("http://{address}"));
+        assert_eq!(Fetched::Downloaded, fetcher.fetch(2024, 1, &path).unwrap());
+        assert_eq!("42\n", fs::read_to_string(&path).unwrap());
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.contains("session=abc"));
+    }
+}
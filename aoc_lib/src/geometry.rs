@@ -0,0 +1,169 @@
+use num::{Integer, Signed};
+
+use super::vec2::Vec2;
+
+/// Where a point lies relatively to a polygon.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+fn edges<T: Copy>(vertices: &[Vec2<T>]) -> impl Iterator<Item = (Vec2<T>, Vec2<T>)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+/// Twice the area of the polygon using the shoelace formula, the sign giving the orientation of
+/// the vertices. Doubling keeps the result exact on integer coordinates.
+pub fn signed_double_area<T: Integer + Signed + Copy>(vertices: &[Vec2<T>]) -> T {
+    edges(vertices).fold(T::zero(), |area, (a, b)| area + a.x * b.y - a.y * b.x)
+}
+
+/// Area of the polygon, rounded down when it is not a whole number.
+pub fn area<T: Integer + Signed + Copy>(vertices: &[Vec2<T>]) -> T {
+    signed_double_area(vertices).abs() / (T::one() + T::one())
+}
+
+/// Number of integer points lying on the edges of the polygon.
+pub fn boundary_points<T: Integer + Signed + Copy>(vertices: &[Vec2<T>]) -> T {
+    edges(vertices).fold(T::zero(), |count, (a, b)| {
+        count + (b.x - a.x).abs().gcd(&(b.y - a.y).abs())
+    })
+}
+
+/// Number of integer points strictly inside a polygon with integer vertices, by Pick's theorem
+/// (`A = I + B / 2 - 1`).
+pub fn interior_points<T: Integer + Signed + Copy>(vertices: &[Vec2<T>]) -> T {
+    let two = T::one() + T::one();
+    (signed_double_area(vertices).abs() - boundary_points(vertices) + two) / two
+}
+
+/// Locate `point` relatively to the polygon by casting a ray, exact on integer coordinates.
+pub fn locate<T: Integer + Signed + Copy>(point: Vec2<T>, vertices: &[Vec2<T>]) -> Location {
+    let mut inside = false;
+    for (a, b) in edges(vertices) {
+        let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+        if cross.is_zero()
+            && a.x.min(b.x) <= point.x
+            && point.x <= a.x.max(b.x)
+            && a.y.min(b.y) <= point.y
+            && point.y <= a.y.max(b.y)
+        {
+            return Location::Boundary;
+        }
+        if (a.y > point.y) != (b.y > point.y) {
+            // does the edge cross the horizontal ray going right from the point
+            let lhs = (point.x - a.x) * (b.y - a.y);
+            let rhs = (point.y - a.y) * (b.x - a.x);
+            if (b.y > a.y && lhs < rhs) || (b.y < a.y && lhs > rhs) {
+                inside = !inside;
+            }
+        }
+    }
+    if inside {
+        Location::Inside
+    } else {
+        Location::Outside
+    }
+}
+
+/// Whether the axis aligned rectangle of opposite corners `a` and `b` lies inside the polygon,
+/// its boundary included. The polygon edges must be axis aligned too.
+pub fn rect_in_polygon<T: Integer + Signed + Copy>(
+    a: Vec2<T>,
+    b: Vec2<T>,
+    vertices: &[Vec2<T>],
+) -> bool {
+    let min = Vec2::new(a.x.min(b.x), a.y.min(b.y));
+    let max = Vec2::new(a.x.max(b.x), a.y.max(b.y));
+    let corners = [min, Vec2::new(min.x, max.y), max, Vec2::new(max.x, min.y)];
+    if corners
+        .iter()
+        .any(|&corner| locate(corner, vertices) == Location::Outside)
+    {
+        return false;
+    }
+    // with its corners inside, the rectangle can only get out of the polygon if an edge goes
+    // through it
+    !edges(vertices).any(|(p, q)| {
+        if p.x == q.x {
+            min.x < p.x && p.x < max.x && crosses(p.y.min(q.y), p.y.max(q.y), min.y, max.y)
+        } else {
+            min.y < p.y && p.y < max.y && crosses(p.x.min(q.x), p.x.max(q.x), min.x, max.x)
+        }
+    })
+}
+
+// whether the open segment `(lo, hi)` of an edge overlaps the inside of `[start, end]`, which may
+// be a single point
+fn crosses<T: Integer + Copy>(lo: T, hi: T, start: T, end: T) -> bool {
+    if start == end {
+        lo < start && start < hi
+    } else {
+        lo.max(start) < hi.min(end)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::vec2::Vec2;
+
+    use super::{
+        area, boundary_points, interior_points, locate, rect_in_polygon, signed_double_area,
+        Location,
+    };
+
+    fn polygon(points: &[(i64, i64)]) -> Vec<Vec2<i64>> {
+        points.iter().map(|&(x, y)| Vec2::new(x, y)).collect()
+    }
+
+    #[test]
+    fn test_area_and_pick() {
+        let square = polygon(&[(0, 0), (4, 0), (4, 4), (0, 4)]);
+        assert_eq!(32, signed_double_area(&square));
+        assert_eq!(16, area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(9, interior_points(&square));
+        let reversed = square.iter().rev().copied().collect::<Vec<_>>();
+        assert_eq!(-32, signed_double_area(&reversed));
+        let triangle = polygon(&[(0, 0), (3, 0), (0, 3)]);
+        assert_eq!(4, area(&triangle));
+        assert_eq!(9, boundary_points(&triangle));
+        assert_eq!(1, interior_points(&triangle));
+    }
+
+    #[test]
+    fn test_locate() {
+        let triangle = polygon(&[(0, 0), (6, 0), (0, 6)]);
+        assert_eq!(Location::Inside, locate(Vec2::new(1, 1), &triangle));
+        assert_eq!(Location::Boundary, locate(Vec2::new(3, 3), &triangle));
+        assert_eq!(Location::Boundary, locate(Vec2::new(0, 6), &triangle));
+        assert_eq!(Location::Outside, locate(Vec2::new(4, 4), &triangle));
+        assert_eq!(Location::Outside, locate(Vec2::new(-1, 0), &triangle));
+    }
+
+    #[test]
+    fn test_rect_in_polygon() {
+        // U shape, open at the top between x = 3 and x = 7
+        let u = polygon(&[
+            (0, 0),
+            (3, 0),
+            (3, 5),
+            (7, 5),
+            (7, 0),
+            (10, 0),
+            (10, 8),
+            (0, 8),
+        ]);
+        assert!(rect_in_polygon(Vec2::new(0, 0), Vec2::new(3, 8), &u));
+        assert!(rect_in_polygon(Vec2::new(0, 8), Vec2::new(10, 5), &u));
+        assert!(!rect_in_polygon(Vec2::new(0, 0), Vec2::new(10, 8), &u));
+        assert!(!rect_in_polygon(Vec2::new(1, 2), Vec2::new(9, 6), &u));
+        assert!(!rect_in_polygon(Vec2::new(0, 2), Vec2::new(10, 2), &u));
+        assert!(rect_in_polygon(Vec2::new(0, 6), Vec2::new(10, 6), &u));
+    }
+}
@@ -0,0 +1,381 @@
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Range,
+};
+
+/// Graph whose nodes are named by labels, e.g. the `&str` names of the input, interned to the
+/// compact ids `0..len` used by every algorithm.
+#[derive(Debug, Clone)]
+pub struct Graph<L> {
+    labels: Vec<L>,
+    ids: HashMap<L, usize>,
+    adjacency: Vec<Vec<usize>>,
+    directed: bool,
+}
+
+impl<L: Hash + Eq + Clone> Graph<L> {
+    pub fn directed() -> Self {
+        Self::new(true)
+    }
+
+    pub fn undirected() -> Self {
+        Self::new(false)
+    }
+
+    fn new(directed: bool) -> Self {
+        Self {
+            labels: vec![],
+            ids: HashMap::new(),
+            adjacency: vec![],
+            directed,
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    /// Id of the node `label`, added if it is new.
+    pub fn node(&mut self, label: L) -> usize {
+        if let Some(&id) = self.ids.get(&label) {
+            return id;
+        }
+        let id = self.labels.len();
+        self.ids.insert(label.clone(), id);
+        self.labels.push(label);
+        self.adjacency.push(vec![]);
+        id
+    }
+
+    /// Add the edge from `a` to `b`, adding the nodes if needed, and return their ids.
+    pub fn add_edge(&mut self, a: L, b: L) -> (usize, usize) {
+        let (a, b) = (self.node(a), self.node(b));
+        self.adjacency[a].push(b);
+        if !self.directed && a != b {
+            self.adjacency[b].push(a);
+        }
+        (a, b)
+    }
+
+    pub fn id(&self, label: &L) -> Option<usize> {
+        self.ids.get(label).copied()
+    }
+}
+
+impl<L> Graph<L> {
+    pub fn label(&self, id: usize) -> &L {
+        &self.labels[id]
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    pub fn nodes(&self) -> Range<usize> {
+        0..self.len()
+    }
+
+    /// Nodes reached by the edges going out of `id`, in insertion order.
+    pub fn neighbors(&self, id: usize) -> &[usize] {
+        &self.adjacency[id]
+    }
+
+    pub fn has_edge(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].contains(&b)
+    }
+
+    /// Every edge, once even when undirected.
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.nodes().flat_map(move |a| {
+            self.adjacency[a]
+                .iter()
+                .filter(move |&&b| self.directed || a <= b)
+                .map(move |&b| (a, b))
+        })
+    }
+
+    /// Nodes ordered so that every edge goes forward, `None` if the graph has a cycle or is
+    /// undirected.
+    pub fn toposort(&self) -> Option<Vec<usize>> {
+        if !self.directed {
+            return None;
+        }
+        let mut incoming = vec![0_usize; self.len()];
+        for (_, b) in self.edges() {
+            incoming[b] += 1;
+        }
+        let mut queue = self
+            .nodes()
+            .filter(|&id| incoming[id] == 0)
+            .collect::<VecDeque<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for &next in &self.adjacency[id] {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Number of paths from `from` to `to` in a directed acyclic graph, `None` if it has a cycle
+    /// or is undirected.
+    pub fn count_paths(&self, from: usize, to: usize) -> Option<u64> {
+        let order = self.toposort()?;
+        Some(self.count_paths_in(&order, from, to))
+    }
+
+    /// Number of paths from `from` to `to` going through all of the `via` nodes, in any order.
+    /// `None` like [`Graph::count_paths`].
+    pub fn count_paths_via(&self, from: usize, to: usize, via: &[usize]) -> Option<u64> {
+        let order = self.toposort()?;
+        let mut rank = vec![0; self.len()];
+        for (i, &id) in order.iter().enumerate() {
+            rank[id] = i;
+        }
+        // a path can only meet the required nodes in topological order
+        let mut stops = via.to_vec();
+        stops.sort_unstable_by_key(|&id| rank[id]);
+        let stops = [from]
+            .into_iter()
+            .chain(stops)
+            .chain([to])
+            .collect::<Vec<_>>();
+        Some(
+            stops
+                .windows(2)
+                .map(|w| self.count_paths_in(&order, w[0], w[1]))
+                .product(),
+        )
+    }
+
+    fn count_paths_in(&self, order: &[usize], from: usize, to: usize) -> u64 {
+        let mut paths = vec![0; self.len()];
+        paths[from] = 1;
+        for &id in order {
+            if paths[id] == 0 {
+                continue;
+            }
+            for &next in &self.adjacency[id] {
+                paths[next] += paths[id];
+            }
+        }
+        paths[to]
+    }
+
+    /// Every maximal clique of an undirected graph, with the Bron–Kerbosch algorithm and
+    /// pivoting. Members of each clique are sorted.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let neighbors = self
+            .adjacency
+            .iter()
+            .map(|adjacent| adjacent.iter().copied().collect::<HashSet<_>>())
+            .collect::<Vec<_>>();
+        let mut cliques = vec![];
+        bron_kerbosch(
+            &neighbors,
+            &mut vec![],
+            self.nodes().collect(),
+            HashSet::new(),
+            &mut cliques,
+        );
+        cliques
+    }
+
+    /// Global minimum cut of the graph seen as undirected, each edge weighting one, with the
+    /// Stoer–Wagner algorithm. Returns the number of edges cut and the nodes of one side, `None`
+    /// with fewer than two nodes.
+    pub fn min_cut(&self) -> Option<(usize, Vec<usize>)> {
+        let n = self.len();
+        if n < 2 {
+            return None;
+        }
+        let mut weights = vec![HashMap::<usize, usize>::new(); n];
+        for (a, b) in self.edges() {
+            if a != b {
+                *weights[a].entry(b).or_default() += 1;
+                *weights[b].entry(a).or_default() += 1;
+            }
+        }
+        let mut members = self.nodes().map(|id| vec![id]).collect::<Vec<_>>();
+        let mut active = self.nodes().collect::<Vec<_>>();
+        let mut best: Option<(usize, Vec<usize>)> = None;
+        while active.len() > 1 {
+            // maximum adjacency search: repeatedly add the node most connected to the added ones
+            let mut added = vec![false; n];
+            let mut connection = vec![0; n];
+            let mut heap = active.iter().map(|&id| (0, id)).collect::<BinaryHeap<_>>();
+            let mut order = Vec::with_capacity(active.len());
+            while let Some((weight, id)) = heap.pop() {
+                if added[id] || weight != connection[id] {
+                    continue;
+                }
+                added[id] = true;
+                order.push(id);
+                for (&next, &w) in &weights[id] {
+                    if !added[next] {
+                        connection[next] += w;
+                        heap.push((connection[next], next));
+                    }
+                }
+            }
+            let (s, t) = (order[order.len() - 2], order[order.len() - 1]);
+            if best.as_ref().is_none_or(|(cut, _)| connection[t] < *cut) {
+                best = Some((connection[t], members[t].clone()));
+            }
+            // merge the last node into the one before
+            for (next, w) in std::mem::take(&mut weights[t]) {
+                weights[next].remove(&t);
+                if next != s {
+                    *weights[s].entry(next).or_default() += w;
+                    *weights[next].entry(s).or_default() += w;
+                }
+            }
+            let merged = std::mem::take(&mut members[t]);
+            members[s].extend(merged);
+            active.retain(|&id| id != t);
+        }
+        best.map(|(cut, mut side)| {
+            side.sort_unstable();
+            (cut, side)
+        })
+    }
+}
+
+fn bron_kerbosch(
+    neighbors: &[HashSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    if candidates.is_empty() && excluded.is_empty() {
+        let mut found = clique.clone();
+        found.sort_unstable();
+        cliques.push(found);
+        return;
+    }
+    // the neighbors of the pivot are reached through the pivot or one of its non neighbors
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|&&u| candidates.intersection(&neighbors[u]).count())
+        .copied()
+        .unwrap();
+    let branches = candidates
+        .difference(&neighbors[pivot])
+        .copied()
+        .collect::<Vec<_>>();
+    for v in branches {
+        clique.push(v);
+        bron_kerbosch(
+            neighbors,
+            clique,
+            candidates.intersection(&neighbors[v]).copied().collect(),
+            excluded.intersection(&neighbors[v]).copied().collect(),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Graph;
+
+    fn directed(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::directed();
+        for &(a, b) in edges {
+            graph.add_edge(a, b);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = Graph::undirected();
+        assert_eq!((0, 1), graph.add_edge("kh", "tc"));
+        assert_eq!((2, 0), graph.add_edge("qp", "kh"));
+        assert_eq!(1, graph.node("tc"));
+        assert_eq!(3, graph.len());
+        assert_eq!(Some(2), graph.id(&"qp"));
+        assert_eq!(&"tc", graph.label(1));
+        assert_eq!(&[1, 2], graph.neighbors(0));
+        assert!(graph.has_edge(2, 0));
+        assert_eq!(vec![(0, 1), (0, 2)], graph.edges().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_paths() {
+        let graph = directed(&[
+            ("svr", "aaa"),
+            ("svr", "bbb"),
+            ("aaa", "fft"),
+            ("bbb", "fft"),
+            ("bbb", "dac"),
+            ("fft", "dac"),
+            ("fft", "out"),
+            ("dac", "out"),
+        ]);
+        let order = graph.toposort().unwrap();
+        for (a, b) in graph.edges() {
+            let position = |id| order.iter().position(|&o| o == id);
+            assert!(position(a) < position(b));
+        }
+        let id = |label| graph.id(&label).unwrap();
+        assert_eq!(Some(5), graph.count_paths(id("svr"), id("out")));
+        let via = [id("dac"), id("fft")];
+        assert_eq!(Some(2), graph.count_paths_via(id("svr"), id("out"), &via));
+        let cyclic = directed(&[("a", "b"), ("b", "a")]);
+        assert_eq!(None, cyclic.toposort());
+        assert_eq!(None, cyclic.count_paths(0, 1));
+        let mut triangle = Graph::undirected();
+        triangle.add_edge("a", "b");
+        triangle.add_edge("b", "c");
+        triangle.add_edge("c", "a");
+        assert_eq!(None, triangle.toposort());
+        assert_eq!(None, triangle.count_paths_via(0, 2, &[1]));
+    }
+
+    #[test]
+    fn test_cliques_and_cut() {
+        let mut graph = Graph::undirected();
+        // two squares with their diagonals, linked by two edges
+        for (a, b) in [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)] {
+            graph.add_edge(a, b);
+            graph.add_edge(a + 4, b + 4);
+        }
+        graph.add_edge(0, 4);
+        graph.add_edge(3, 7);
+        let labels = |ids: &[usize]| {
+            let mut labels = ids.iter().map(|&id| *graph.label(id)).collect::<Vec<_>>();
+            labels.sort_unstable();
+            labels
+        };
+        let mut cliques = graph
+            .maximal_cliques()
+            .iter()
+            .map(|clique| labels(clique))
+            .collect::<Vec<_>>();
+        cliques.sort();
+        assert_eq!(
+            vec![vec![0, 1, 2, 3], vec![0, 4], vec![3, 7], vec![4, 5, 6, 7]],
+            cliques
+        );
+        let (cut, side) = graph.min_cut().unwrap();
+        assert_eq!(2, cut);
+        let side = labels(&side);
+        assert!(side == vec![0, 1, 2, 3] || side == vec![4, 5, 6, 7]);
+        assert_eq!(None, Graph::<u8>::undirected().min_cut());
+    }
+}
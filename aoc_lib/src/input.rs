@@ -5,7 +5,14 @@ use std::{
 
 pub fn read_file(path: &str) -> io::Result<Vec<String>> {
     let file = File::open(path)?;
-    let reader = BufReader::new(file);
+    read_lines(BufReader::new(file))
+}
+
+pub fn read_stdin() -> io::Result<Vec<String>> {
+    read_lines(io::stdin().lock())
+}
+
+pub fn read_lines(reader: impl BufRead) -> io::Result<Vec<String>> {
     let mut output: Vec<String> = vec![];
     let lines = reader.lines();
     for line in lines {
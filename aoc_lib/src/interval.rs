@@ -0,0 +1,206 @@
+use std::ops::{Range, RangeInclusive};
+
+use num::Integer;
+
+/// Set of integers stored as sorted, disjoint and non adjacent half open ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<T: Integer + Copy> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // sort and merge overlapping or touching ranges, dropping the empty ones
+    fn normalized(mut ranges: Vec<Range<T>>) -> Self {
+        ranges.retain(|r| r.start < r.end);
+        ranges.sort_unstable_by_key(|r| r.start);
+        let mut merged: Vec<Range<T>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        let mut ranges = std::mem::take(&mut self.ranges);
+        ranges.push(range);
+        *self = Self::normalized(ranges);
+    }
+
+    pub fn insert_inclusive(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        self.insert(start..end + T::one());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Number of integers in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |len, r| len + r.end - r.start)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|r| r.end - T::one())
+    }
+
+    pub fn contains(&self, value: &T) -> bool {
+        let index = self.ranges.partition_point(|r| r.end <= *value);
+        self.ranges.get(index).is_some_and(|r| r.start <= *value)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalized(self.iter().chain(other.iter()).collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Integers of `self` that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(b) = other.ranges.get(j) {
+                if b.end <= start {
+                    j += 1;
+                    continue;
+                }
+                if b.start >= range.end {
+                    break;
+                }
+                if b.start > start {
+                    ranges.push(start..b.start);
+                }
+                start = start.max(b.end);
+                if b.end >= range.end {
+                    break;
+                }
+                j += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        Self { ranges }
+    }
+
+    /// Split into the integers below `at`, and the ones at or above it.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = vec![];
+        let mut above = vec![];
+        for range in self.iter() {
+            if range.end <= at {
+                below.push(range);
+            } else if range.start >= at {
+                above.push(range);
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+        (Self { ranges: below }, Self { ranges: above })
+    }
+
+    /// Add `offset` to every integer of the set.
+    pub fn shift(&self, offset: T) -> Self {
+        let ranges = self
+            .iter()
+            .map(|r| r.start + offset..r.end + offset)
+            .collect();
+        Self { ranges }
+    }
+}
+
+impl<T: Integer + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::normalized(vec![range])
+    }
+}
+
+impl<T: Integer + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        Self::normalized(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IntervalSet;
+
+    #[test]
+    fn test_normalize() {
+        let mut set = IntervalSet::from_iter([10..14, 3..6, 16..21, 12..19, 5..5]);
+        assert_eq!(vec![3..6, 10..21], set.iter().collect::<Vec<_>>());
+        set.insert_inclusive(6..=8);
+        assert_eq!(vec![3..9, 10..21], set.iter().collect::<Vec<_>>());
+        assert_eq!(17, set.len());
+        assert!(set.contains(&3) && set.contains(&20) && !set.contains(&9));
+        assert_eq!((Some(3), Some(20)), (set.min(), set.max()));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([0..10, 20..30]);
+        let b = IntervalSet::from_iter([5..25, 28..40]);
+        assert_eq!(IntervalSet::from(0..40), a.union(&b));
+        assert_eq!(
+            IntervalSet::from_iter([5..10, 20..25, 28..30]),
+            a.intersection(&b)
+        );
+        assert_eq!(IntervalSet::from_iter([0..5, 25..28]), a.difference(&b));
+        assert_eq!(IntervalSet::from_iter([10..20, 30..40]), b.difference(&a));
+        assert_eq!(IntervalSet::new(), a.difference(&a));
+    }
+
+    #[test]
+    fn test_split_and_shift() {
+        let set = IntervalSet::from_iter([0..10, 20..30]);
+        let (below, above) = set.split_at(25);
+        assert_eq!(IntervalSet::from_iter([0..10, 20..25]), below);
+        assert_eq!(IntervalSet::from(25..30), above);
+        assert_eq!(
+            IntervalSet::from_iter([-5..5, 15..25]),
+            IntervalSet::from_iter([0..10, 20..30]).shift(-5)
+        );
+    }
+}
@@ -1,7 +1,23 @@
 pub mod answer;
+pub mod compress;
+pub mod cycle;
 pub mod directions;
+pub mod error;
+pub mod fetch;
+pub mod geometry;
+pub mod graph;
 pub mod input;
+pub mod interval;
+pub mod linear;
 pub mod maths;
 pub mod matrix;
+pub mod maze;
+pub mod memo;
+pub mod region;
+pub mod registry;
+pub mod search;
 pub mod solution;
+pub mod sparse_grid;
+pub mod submit;
+pub mod union_find;
 pub mod vec2;
@@ -0,0 +1,510 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use num::{rational::Ratio, Integer, One, Signed, ToPrimitive, Zero};
+
+/// Exact rational number, large enough for most puzzle inputs. Use [`num::BigRational`] with
+/// the generic functions when the intermediate values may overflow.
+pub type Rational = Ratio<i128>;
+
+/// Reduced row echelon form of a linear system `A x = b`, obtained by Gauss-Jordan elimination
+/// over exact rationals.
+#[derive(Debug, Clone)]
+pub struct Echelon<T: Clone + Integer> {
+    /// Rows of the augmented matrix `[A | b]`, the last column holding the right hand side.
+    pub rows: Vec<Vec<Ratio<T>>>,
+    /// Pivot column of each of the first rows, in increasing order. The remaining rows only
+    /// hold zero coefficients.
+    pub pivots: Vec<usize>,
+    /// Number of unknowns.
+    pub cols: usize,
+}
+
+impl<T: Clone + Integer + Signed> Echelon<T> {
+    /// Reduce the rows of an augmented matrix, all of them being of the same length.
+    pub fn new(mut rows: Vec<Vec<Ratio<T>>>) -> Self {
+        let cols = rows.first().map_or(0, |row| row.len().saturating_sub(1));
+        let mut pivots = vec![];
+        for col in 0..cols {
+            let r = pivots.len();
+            let Some(pivot) = (r..rows.len()).find(|&i| !rows[i][col].is_zero()) else {
+                continue;
+            };
+            rows.swap(r, pivot);
+            let inverse = rows[r][col].recip();
+            for value in rows[r].iter_mut() {
+                *value = value.clone() * inverse.clone();
+            }
+            let pivot_row = rows[r].clone();
+            for (i, row) in rows.iter_mut().enumerate() {
+                if i == r || row[col].is_zero() {
+                    continue;
+                }
+                let factor = row[col].clone();
+                for (value, p) in row.iter_mut().zip(&pivot_row) {
+                    *value = value.clone() - factor.clone() * p.clone();
+                }
+            }
+            pivots.push(col);
+        }
+        Self { rows, pivots, cols }
+    }
+
+    pub fn rank(&self) -> usize {
+        self.pivots.len()
+    }
+
+    /// Whether the system has at least one solution.
+    pub fn is_consistent(&self) -> bool {
+        self.rows[self.rank()..]
+            .iter()
+            .all(|row| row[self.cols].is_zero())
+    }
+
+    /// Unknowns that can take any value, the others being determined by them.
+    pub fn free_columns(&self) -> Vec<usize> {
+        (0..self.cols)
+            .filter(|col| !self.pivots.contains(col))
+            .collect()
+    }
+
+    /// The solution of the system, `None` if there is none or infinitely many.
+    pub fn solution(&self) -> Option<Vec<Ratio<T>>> {
+        if !self.is_consistent() || self.rank() != self.cols {
+            return None;
+        }
+        Some(
+            self.rows[..self.cols]
+                .iter()
+                .map(|row| row[self.cols].clone())
+                .collect(),
+        )
+    }
+}
+
+/// Solve `A x = b` for integer coefficients, `None` if there is no unique solution.
+pub fn solve<T: Clone + Integer + Signed>(a: &[Vec<T>], b: &[T]) -> Option<Vec<Ratio<T>>> {
+    let rows = a
+        .iter()
+        .zip(b)
+        .map(|(row, rhs)| {
+            row.iter()
+                .chain([rhs])
+                .map(|value| Ratio::from_integer(value.clone()))
+                .collect()
+        })
+        .collect();
+    Echelon::new(rows).solution()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relation {
+    LessEq,
+    Equal,
+    GreaterEq,
+}
+
+/// Integer linear program minimizing `objective · x` under linear constraints, solved by branch
+/// and bound over the LP relaxation. Each variable lies between its bounds, zero and unbounded
+/// by default. Negate the objective to maximize it.
+///
+/// Without upper bounds, a relaxation can stay feasible however deep the branching goes while
+/// no integer point exists, so the search gives up after a number of explored nodes.
+#[derive(Debug, Clone)]
+pub struct IntegerProgram {
+    objective: Vec<i64>,
+    constraints: Vec<(Vec<i64>, Relation, i64)>,
+    bounds: Vec<(i64, Option<i64>)>,
+    node_limit: usize,
+}
+
+const DEFAULT_NODE_LIMIT: usize = 100_000;
+
+/// The branch and bound search of an [`IntegerProgram`] explored its node limit before proving
+/// the optimum.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeLimitReached {
+    /// Best solution found so far, which may not be optimal.
+    pub best: Option<(i64, Vec<i64>)>,
+}
+
+impl Display for NodeLimitReached {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "node limit reached before proving the optimum")?;
+        if let Some((value, _)) = &self.best {
+            write!(f, ", best value found is {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for NodeLimitReached {}
+
+impl IntegerProgram {
+    pub fn minimize(objective: Vec<i64>) -> Self {
+        let bounds = vec![(0, None); objective.len()];
+        Self {
+            objective,
+            constraints: vec![],
+            bounds,
+            node_limit: DEFAULT_NODE_LIMIT,
+        }
+    }
+
+    /// Add the constraint `coefficients · x <relation> rhs`.
+    pub fn with(mut self, coefficients: Vec<i64>, relation: Relation, rhs: i64) -> Self {
+        assert_eq!(self.objective.len(), coefficients.len());
+        self.constraints.push((coefficients, relation, rhs));
+        self
+    }
+
+    pub fn with_bounds(mut self, variable: usize, lower: i64, upper: Option<i64>) -> Self {
+        self.bounds[variable] = (lower, upper);
+        self
+    }
+
+    /// Maximum number of branch and bound nodes explored before giving up.
+    pub fn with_node_limit(mut self, node_limit: usize) -> Self {
+        self.node_limit = node_limit;
+        self
+    }
+
+    /// Optimal value and variables, `None` if there is no solution or the objective is
+    /// unbounded. Fails when the node limit is reached before the optimum is proven.
+    pub fn solve(&self) -> Result<Option<(i64, Vec<i64>)>, NodeLimitReached> {
+        let mut best: Option<(i64, Vec<i64>)> = None;
+        let mut stack = vec![self.bounds.clone()];
+        let mut nodes = 0;
+        while let Some(bounds) = stack.pop() {
+            nodes += 1;
+            if nodes > self.node_limit {
+                return Err(NodeLimitReached { best });
+            }
+            let Some((value, x)) = self.relaxation(&bounds) else {
+                continue;
+            };
+            // the objective being integer, no solution of this branch costs less than the ceil
+            if best
+                .as_ref()
+                .is_some_and(|&(best, _)| value.ceil() >= Rational::from(best as i128))
+            {
+                continue;
+            }
+            match x.iter().position(|v| !v.is_integer()) {
+                Some(i) => {
+                    let floor = to_i64(&x[i].floor());
+                    let mut below = bounds.clone();
+                    below[i].1 = Some(floor);
+                    let mut above = bounds;
+                    above[i].0 = floor + 1;
+                    stack.push(below);
+                    stack.push(above);
+                }
+                None => best = Some((to_i64(&value), x.iter().map(to_i64).collect())),
+            }
+        }
+        Ok(best)
+    }
+
+    // optimum of the problem without the integer constraint, variables being shifted so that
+    // their lower bound is zero
+    fn relaxation(&self, bounds: &[(i64, Option<i64>)]) -> Option<(Rational, Vec<Rational>)> {
+        let rational = |v: i64| Rational::from(v as i128);
+        let mut constraints = vec![];
+        for (coefficients, relation, rhs) in &self.constraints {
+            let shift: i64 = coefficients
+                .iter()
+                .zip(bounds)
+                .map(|(a, (lower, _))| a * lower)
+                .sum();
+            let row = coefficients.iter().map(|&a| rational(a)).collect();
+            constraints.push((row, *relation, rational(rhs - shift)));
+        }
+        for (i, &(lower, upper)) in bounds.iter().enumerate() {
+            if let Some(upper) = upper {
+                if upper < lower {
+                    return None;
+                }
+                let mut row = vec![Rational::zero(); bounds.len()];
+                row[i] = Rational::one();
+                constraints.push((row, Relation::LessEq, rational(upper - lower)));
+            }
+        }
+        let cost = self
+            .objective
+            .iter()
+            .map(|&c| rational(c))
+            .collect::<Vec<_>>();
+        let (value, y) = simplex(&cost, constraints)?;
+        let x = y
+            .iter()
+            .zip(bounds)
+            .map(|(y, (lower, _))| y + rational(*lower))
+            .collect::<Vec<_>>();
+        let shift = cost
+            .iter()
+            .zip(bounds)
+            .fold(Rational::zero(), |acc, (c, (lower, _))| {
+                acc + c * rational(*lower)
+            });
+        Some((value + shift, x))
+    }
+}
+
+fn to_i64(value: &Rational) -> i64 {
+    value
+        .to_integer()
+        .to_i64()
+        .expect("integer program values should fit in an i64")
+}
+
+// minimize `cost · y` for `y >= 0` with the two phases simplex and Bland's rule, `None` if
+// infeasible or unbounded
+fn simplex(
+    cost: &[Rational],
+    constraints: Vec<(Vec<Rational>, Relation, Rational)>,
+) -> Option<(Rational, Vec<Rational>)> {
+    let n = cost.len();
+    // right hand sides must be non negative for the initial basis to be feasible
+    let constraints = constraints
+        .into_iter()
+        .map(|(row, relation, rhs)| {
+            if rhs.is_negative() {
+                let relation = match relation {
+                    Relation::LessEq => Relation::GreaterEq,
+                    Relation::Equal => Relation::Equal,
+                    Relation::GreaterEq => Relation::LessEq,
+                };
+                (row.into_iter().map(|a| -a).collect(), relation, -rhs)
+            } else {
+                (row, relation, rhs)
+            }
+        })
+        .collect::<Vec<(Vec<_>, _, _)>>();
+    let slacks = constraints
+        .iter()
+        .filter(|(_, relation, _)| *relation != Relation::Equal)
+        .count();
+    let artificials = constraints
+        .iter()
+        .filter(|(_, relation, _)| *relation != Relation::LessEq)
+        .count();
+    let total = n + slacks + artificials;
+
+    let mut tableau = Tableau {
+        rows: vec![],
+        basis: vec![],
+    };
+    let (mut slack, mut artificial) = (n, n + slacks);
+    for (coefficients, relation, rhs) in constraints {
+        let mut row = vec![Rational::zero(); total + 1];
+        row[..n].clone_from_slice(&coefficients);
+        row[total] = rhs;
+        match relation {
+            Relation::LessEq => {
+                row[slack] = Rational::one();
+                tableau.basis.push(slack);
+                slack += 1;
+            }
+            Relation::GreaterEq => {
+                row[slack] = -Rational::one();
+                row[artificial] = Rational::one();
+                tableau.basis.push(artificial);
+                slack += 1;
+                artificial += 1;
+            }
+            Relation::Equal => {
+                row[artificial] = Rational::one();
+                tableau.basis.push(artificial);
+                artificial += 1;
+            }
+        }
+        tableau.rows.push(row);
+    }
+
+    // phase one: find a feasible basis by getting rid of the artificial variables
+    let mut phase_one = vec![Rational::zero(); total];
+    for c in &mut phase_one[n + slacks..] {
+        *c = Rational::one();
+    }
+    tableau.optimize(&phase_one, total);
+    if !tableau.value(&phase_one).is_zero() {
+        return None;
+    }
+    let mut i = 0;
+    while i < tableau.rows.len() {
+        if tableau.basis[i] < n + slacks {
+            i += 1;
+            continue;
+        }
+        match (0..n + slacks).find(|&j| !tableau.rows[i][j].is_zero()) {
+            Some(j) => {
+                tableau.pivot(i, j);
+                i += 1;
+            }
+            // redundant constraint
+            None => {
+                tableau.rows.remove(i);
+                tableau.basis.remove(i);
+            }
+        }
+    }
+
+    // phase two: optimize the real objective, artificial variables staying out of the basis
+    let mut phase_two = vec![Rational::zero(); total];
+    phase_two[..n].clone_from_slice(cost);
+    if !tableau.optimize(&phase_two, n + slacks) {
+        return None;
+    }
+    let mut y = vec![Rational::zero(); n];
+    for (row, &basic) in tableau.rows.iter().zip(&tableau.basis) {
+        if basic < n {
+            y[basic] = row[total];
+        }
+    }
+    Some((tableau.value(&phase_two), y))
+}
+
+struct Tableau {
+    rows: Vec<Vec<Rational>>,
+    basis: Vec<usize>,
+}
+
+impl Tableau {
+    fn value(&self, cost: &[Rational]) -> Rational {
+        self.rows
+            .iter()
+            .zip(&self.basis)
+            .fold(Rational::zero(), |acc, (row, &basic)| {
+                acc + cost[basic] * row[row.len() - 1]
+            })
+    }
+
+    fn pivot(&mut self, r: usize, col: usize) {
+        let inverse = self.rows[r][col].recip();
+        for value in self.rows[r].iter_mut() {
+            *value *= inverse;
+        }
+        let pivot_row = self.rows[r].clone();
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            let factor = row[col];
+            if i == r || factor.is_zero() {
+                continue;
+            }
+            for (value, p) in row.iter_mut().zip(&pivot_row) {
+                *value -= factor * p;
+            }
+        }
+        self.basis[r] = col;
+    }
+
+    // minimize with the `allowed` first columns as candidates to enter the basis, false if
+    // unbounded
+    fn optimize(&mut self, cost: &[Rational], allowed: usize) -> bool {
+        loop {
+            let reduced = |j: usize| {
+                self.rows
+                    .iter()
+                    .zip(&self.basis)
+                    .fold(cost[j], |acc, (row, &basic)| acc - cost[basic] * row[j])
+            };
+            let Some(col) = (0..allowed)
+                .filter(|j| !self.basis.contains(j))
+                .find(|&j| reduced(j).is_negative())
+            else {
+                return true;
+            };
+            let last = self.rows.first().map_or(0, |row| row.len() - 1);
+            let leaving = (0..self.rows.len())
+                .filter(|&i| self.rows[i][col].is_positive())
+                .min_by(|&a, &b| {
+                    let ratio_a = self.rows[a][last] / self.rows[a][col];
+                    let ratio_b = self.rows[b][last] / self.rows[b][col];
+                    ratio_a
+                        .cmp(&ratio_b)
+                        .then(self.basis[a].cmp(&self.basis[b]))
+                });
+            match leaving {
+                Some(row) => self.pivot(row, col),
+                None => return false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num::{BigInt, BigRational};
+
+    use super::{solve, Echelon, IntegerProgram, NodeLimitReached, Rational, Relation};
+
+    fn rationals(values: &[i128]) -> Vec<Rational> {
+        values.iter().map(|&v| Rational::from(v)).collect()
+    }
+
+    #[test]
+    fn test_solve() {
+        let a = vec![vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]];
+        assert_eq!(Some(rationals(&[2, 3, -1])), solve(&a, &[8, -11, -3]));
+        let x = solve(&[vec![2, 0], vec![0, 3]], &[1, 1]).unwrap();
+        assert_eq!(vec![Rational::new(1, 2), Rational::new(1, 3)], x);
+        assert_eq!(None, solve(&[vec![1, 1], vec![2, 2]], &[1, 3]));
+        let big = solve(
+            &[vec![BigInt::from(10).pow(40)]],
+            &[BigInt::from(10).pow(41)],
+        );
+        assert_eq!(Some(vec![BigRational::from_integer(BigInt::from(10))]), big);
+    }
+
+    #[test]
+    fn test_echelon() {
+        let rows = vec![rationals(&[1, 2, 1, 4]), rationals(&[2, 4, 0, 6])];
+        let echelon = Echelon::new(rows);
+        assert_eq!(2, echelon.rank());
+        assert!(echelon.is_consistent());
+        assert_eq!(vec![1], echelon.free_columns());
+        assert_eq!(None, echelon.solution());
+        let inconsistent = Echelon::new(vec![rationals(&[1, 1, 1]), rationals(&[1, 1, 2])]);
+        assert!(!inconsistent.is_consistent());
+    }
+
+    #[test]
+    fn test_integer_program() {
+        // the LP optimum (x = 2.4, y = 2.4) is not integer
+        let program = IntegerProgram::minimize(vec![0, -1])
+            .with(vec![-1, 1], Relation::LessEq, 1)
+            .with(vec![3, 2], Relation::LessEq, 12)
+            .with(vec![2, 3], Relation::LessEq, 12);
+        assert_eq!(Some(-2), program.solve().unwrap().map(|(value, _)| value));
+        // the optimum is found by the fourth node but not yet proven
+        let limited = program.clone().with_node_limit(4).solve();
+        assert_eq!(
+            Err(NodeLimitReached {
+                best: Some((-2, vec![2, 2]))
+            }),
+            limited
+        );
+        let program = IntegerProgram::minimize(vec![1, 1, 1])
+            .with(vec![1, 1, 0], Relation::Equal, 4)
+            .with(vec![0, 1, 1], Relation::Equal, 5)
+            .with(vec![0, 0, 0], Relation::Equal, 0);
+        assert_eq!(Some((5, vec![0, 4, 1])), program.solve().unwrap());
+        let bounded = IntegerProgram::minimize(vec![1]).with_bounds(0, 3, Some(7));
+        assert_eq!(Some((3, vec![3])), bounded.solve().unwrap());
+        let infeasible = IntegerProgram::minimize(vec![1, 1]).with(vec![2, 2], Relation::Equal, 3);
+        assert_eq!(None, infeasible.solve().unwrap());
+        let unbounded = IntegerProgram::minimize(vec![-1]);
+        assert_eq!(None, unbounded.solve().unwrap());
+    }
+
+    #[test]
+    fn test_integer_program_without_integer_point() {
+        // every relaxation is feasible, the parity of 2x - 2y never matching 1
+        let program = IntegerProgram::minimize(vec![0, 0]).with(vec![2, -2], Relation::Equal, 1);
+        let limited = program.clone().with_node_limit(1000).solve();
+        assert_eq!(Err(NodeLimitReached { best: None }), limited);
+        assert!(program.solve().is_err());
+    }
+}
@@ -1,11 +1,197 @@
-fn gcd(a: i64, b: i64) -> i64 {
-    if b == 0 {
-        a.abs()
+use num::{integer::Roots, FromPrimitive, Integer, Signed, ToPrimitive};
+
+/// Greatest common divisor, always non negative.
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// Least common multiple, always non negative.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    a.lcm(&b)
+}
+
+/// Greatest common divisor of all the values, zero for an empty iterator.
+pub fn gcd_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::zero(), |acc, v| acc.gcd(&v))
+}
+
+/// Least common multiple of all the values, one for an empty iterator.
+pub fn lcm_all<T: Integer>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::one(), |acc, v| acc.lcm(&v))
+}
+
+/// Extended Euclidean algorithm, returns `(g, x, y)` such that `a * x + b * y = g` where `g` is
+/// the greatest common divisor of `a` and `b`.
+pub fn extended_gcd<T: Integer + Signed + Copy>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while !r.is_zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r.is_negative() {
+        (-old_r, -old_x, -old_y)
     } else {
-        gcd(b, a % b)
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Inverse of `a` modulo `modulus` in `[0, modulus)`, `None` if they are not coprime.
+pub fn mod_inverse<T: Integer + Signed + Copy>(a: T, modulus: T) -> Option<T> {
+    let (g, x, _) = extended_gcd(a, modulus);
+    g.is_one().then(|| x.mod_floor(&modulus))
+}
+
+/// `base ^ exp` modulo `modulus` by squaring, twice the modulus must fit in `T`.
+pub fn mod_pow<T: Integer + Copy + ToPrimitive + FromPrimitive>(
+    base: T,
+    mut exp: T,
+    modulus: T,
+) -> T {
+    let two = T::one() + T::one();
+    let mut base = base.mod_floor(&modulus);
+    let mut result = T::one().mod_floor(&modulus);
+    while exp > T::zero() {
+        if exp.is_odd() {
+            result = mod_mul(result, base, modulus);
+        }
+        base = mod_mul(base, base, modulus);
+        exp = exp / two;
+    }
+    result
+}
+
+/// `a * b` modulo `modulus`. The product is computed in an `i128` when the modulus is below
+/// `2^63`, otherwise by doubling and adding so that it never overflows as long as twice the
+/// modulus fits in `T`.
+pub fn mod_mul<T: Integer + Copy + ToPrimitive + FromPrimitive>(a: T, b: T, modulus: T) -> T {
+    let mut a = a.mod_floor(&modulus);
+    let mut b = b.mod_floor(&modulus);
+    if let (Some(x), Some(y), Some(m)) = (a.to_i128(), b.to_i128(), modulus.to_i128()) {
+        if 0 < m && m < 1 << 63 {
+            if let Some(product) = T::from_i128(x * y % m) {
+                return product;
+            }
+        }
+    }
+    let two = T::one() + T::one();
+    let mut result = T::zero();
+    while b > T::zero() {
+        if b.is_odd() {
+            result = (result + a).mod_floor(&modulus);
+        }
+        a = (a + a).mod_floor(&modulus);
+        b = b / two;
+    }
+    result
+}
+
+/// Chinese remainder theorem over `(residue, modulus)` pairs, the moduli not having to be
+/// coprime. Returns `(x, m)` where `m` is the lcm of the moduli and `x` in `[0, m)` is the only
+/// solution modulo `m`, or `None` if the congruences are incompatible or a modulus is not
+/// positive.
+pub fn crt<T: Integer + Signed + Copy + ToPrimitive + FromPrimitive>(
+    congruences: &[(T, T)],
+) -> Option<(T, T)> {
+    let mut x = T::zero();
+    let mut m = T::one();
+    for &(residue, modulus) in congruences {
+        if !modulus.is_positive() {
+            return None;
+        }
+        let (g, p, _) = extended_gcd(m, modulus);
+        let diff = residue - x;
+        if !diff.is_multiple_of(&g) {
+            return None;
+        }
+        // m * p = g (mod modulus), so x + m * p * diff / g solves both congruences
+        let step = modulus / g;
+        let k = mod_mul(diff / g, p, step);
+        x = x + m * k;
+        m = m * step;
+        x = x.mod_floor(&m);
     }
+    Some((x, m))
 }
 
-pub fn lcm(a: i64, b: i64) -> i64 {
-    (a / gcd(a, b)) * b
+/// Integer square root, rounded down. Panics on negative values.
+pub fn isqrt<T: Integer + Roots>(n: T) -> T {
+    n.sqrt()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        crt, extended_gcd, gcd, gcd_all, isqrt, lcm, lcm_all, mod_inverse, mod_mul, mod_pow,
+    };
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(6, gcd(-12, 18));
+        assert_eq!(36, lcm(12_u32, 18));
+        assert_eq!(4, gcd_all([8, 12, 20]));
+        assert_eq!(60, lcm_all([4_i64, 6, 10, 15]));
+        assert_eq!(1, lcm_all(Vec::<i64>::new()));
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        let (g, x, y) = extended_gcd(240, 46);
+        assert_eq!(2, g);
+        assert_eq!(g, 240 * x + 46 * y);
+        let (g, x, y) = extended_gcd(-15, 6);
+        assert_eq!(3, g);
+        assert_eq!(g, -15 * x + 6 * y);
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(6, 9));
+        assert_eq!(445, mod_pow(4, 13, 497));
+        assert_eq!(0, mod_pow(5, 0, 1));
+        assert_eq!(9, mod_mul(-4_i32, 6, 11));
+        // twice the modulus fits in an i128, its square does not
+        let huge = i128::MAX / 2;
+        assert_eq!(huge - 4, mod_mul(huge - 2, 2, huge));
+        let modulus = 1_000_000_016_000_000_063_i64;
+        assert_eq!(
+            176192268561192730,
+            mod_mul(123456789123456789, 987654321987654321, modulus)
+        );
+        assert_eq!(
+            311334396425677441,
+            mod_pow(3, 1_000_000_000_000_000_000, modulus)
+        );
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((10, 12)), crt(&[(2, 4), (4, 6)]));
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+        assert_eq!(None, crt(&[(1, 4), (0, 0)]));
+        assert_eq!(None, crt(&[(1, -4)]));
+        assert_eq!(Some((0, 1)), crt::<i64>(&[]));
+        // the intermediate products do not fit in an i64
+        assert_eq!(
+            Some((500000007500000029, 1000000016000000063)),
+            crt(&[(1_i64, 1_000_000_007), (2, 1_000_000_009)])
+        );
+        assert_eq!(
+            Some((728209000115446490, 1000002999988999967)),
+            crt(&[(123456789_i64, 999999999989), (5, 1000003)])
+        );
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(4, isqrt(24_u64));
+        assert_eq!(5, isqrt(25_i32));
+        assert_eq!(0, isqrt(0_usize));
+    }
 }
@@ -3,7 +3,11 @@ use std::{
     ops::{Index, IndexMut},
 };
 
-use super::vec2::Vec2;
+use super::{
+    directions::{Advance, Direction, ExtendedCardinal},
+    error::AocError,
+    vec2::Vec2,
+};
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct Matrix<T> {
@@ -95,6 +99,80 @@ where
     }
 }
 
+impl<T: Clone> Matrix<T> {
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |pos| {
+            self[Vec2::new(pos.y, pos.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |pos| {
+            self[Vec2::new(pos.y, self.rows - 1 - pos.x)].clone()
+        })
+    }
+
+    /// Rotate a quarter turn counter clockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.cols, self.rows, |pos| {
+            self[Vec2::new(self.cols - 1 - pos.y, pos.x)].clone()
+        })
+    }
+
+    /// Mirror left and right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.rows, self.cols, |pos| {
+            self[Vec2::new(self.cols - 1 - pos.x, pos.y)].clone()
+        })
+    }
+
+    /// Mirror top and bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.rows, self.cols, |pos| {
+            self[Vec2::new(pos.x, self.rows - 1 - pos.y)].clone()
+        })
+    }
+}
+
+/// Borrowed rectangular part of a [`Matrix`], indexed relatively to its top left corner.
+#[derive(Clone, Copy)]
+pub struct View<'a, T> {
+    matrix: &'a Matrix<T>,
+    origin: Vec2<usize>,
+    pub rows: usize,
+    pub cols: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn get(&self, pos: Vec2<usize>) -> Option<&'a T> {
+        (pos.x < self.cols && pos.y < self.rows).then(|| &self.matrix[self.origin + pos])
+    }
+
+    pub fn row(&self, y: usize) -> &'a [T] {
+        &self.matrix.row(self.origin.y + y)[self.origin.x..self.origin.x + self.cols]
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.rows).map(|y| self.row(y))
+    }
+}
+
+impl<T: Clone> View<'_, T> {
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |pos| self[pos].clone())
+    }
+}
+
+impl<T> Index<Vec2<usize>> for View<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: Vec2<usize>) -> &Self::Output {
+        assert!(index.x < self.cols && index.y < self.rows);
+        &self.matrix[self.origin + index]
+    }
+}
+
 impl<T: PartialEq> Matrix<T> {
     // find the first `el` and return it's coordinates
     pub fn find(&self, el: T) -> Option<Vec2<usize>> {
@@ -120,17 +198,164 @@ impl Matrix<char> {
         }
         matrix
     }
+
+    pub fn try_from_chars(input: &[String]) -> Result<Self, AocError> {
+        Self::try_from_rows(input.iter().map(|line| line.chars().collect()).collect())
+    }
 }
 
-impl<T: Clone> From<Vec<Vec<T>>> for Matrix<T> {
-    fn from(value: Vec<Vec<T>>) -> Self {
+impl<T> Matrix<T> {
+    /// Build a matrix from its rows, failing on the first row whose length differs from the first one.
+    pub fn try_from_rows(value: Vec<Vec<T>>) -> Result<Self, AocError> {
         let rows = value.len();
         let cols = if rows > 0 { value[0].len() } else { 0 };
-        if value.iter().any(|r| r.len() != cols) {
-            panic!("Not from a matrix format");
+        if let Some((index, row)) = value.iter().enumerate().find(|(_, r)| r.len() != cols) {
+            return Err(AocError::at_line(
+                index,
+                format!("expected a row of {cols} cells, found {}", row.len()),
+            ));
         }
         let data = value.into_iter().flatten().collect();
-        Matrix { rows, cols, data }
+        Ok(Matrix { rows, cols, data })
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Vec2<usize>) -> T) -> Self {
+        let data = (0..rows)
+            .flat_map(|y| (0..cols).map(move |x| Vec2::new(x, y)))
+            .map(&mut f)
+            .collect();
+        Self { rows, cols, data }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.data[y * self.cols..(y + 1) * self.cols]
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.cols,
+            "column {x} out of a matrix of {} columns",
+            self.cols
+        );
+        self.data[x..]
+            .iter()
+            .step_by(self.cols.max(1))
+            .take(self.rows)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(|y| self.row(y))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|x| self.column(x))
+    }
+
+    /// Positions and values met walking from `pos` (included) towards `direction` until leaving
+    /// the matrix.
+    pub fn ray<D: Direction>(
+        &self,
+        pos: Vec2<usize>,
+        direction: D,
+    ) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        let offset = direction.to_offset::<isize>();
+        (0..).map_while(move |step| {
+            let next = Vec2::<isize>::from(pos) + offset * step;
+            let next = Vec2::<usize>::try_from(&next)
+                .ok()
+                .filter(|next| next.x < self.cols && next.y < self.rows)?;
+            Some((next, &self[next]))
+        })
+    }
+
+    /// Every diagonal going down to the right, starting with the one of the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.rows)
+            .rev()
+            .map(|y| Vec2::new(0, y))
+            .chain((1..self.cols).map(|x| Vec2::new(x, 0)));
+        starts.map(|start| {
+            self.ray(start, ExtendedCardinal::SouthEast)
+                .map(|(_, value)| value)
+        })
+    }
+
+    /// Every diagonal going down to the left, starting with the one of the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.cols)
+            .map(|x| Vec2::new(x, 0))
+            .chain((1..self.rows).map(|y| Vec2::new(self.cols.saturating_sub(1), y)));
+        starts.map(|start| {
+            self.ray(start, ExtendedCardinal::SouthWest)
+                .map(|(_, value)| value)
+        })
+    }
+
+    /// A view on the `rows` x `cols` rectangle whose top left corner is `origin`, `None` if it
+    /// does not fit inside the matrix.
+    pub fn view(&self, origin: Vec2<usize>, rows: usize, cols: usize) -> Option<View<'_, T>> {
+        (origin.y + rows <= self.rows && origin.x + cols <= self.cols).then_some(View {
+            matrix: self,
+            origin,
+            rows,
+            cols,
+        })
+    }
+
+    /// Positions and values of the in-bounds neighbors of `pos`, in the clockwise order of `D`.
+    pub fn neighbors<D: Direction>(
+        &self,
+        pos: Vec2<usize>,
+    ) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        self.neighbors_with_direction::<D>(pos)
+            .map(|(_, pos, value)| (pos, value))
+    }
+
+    /// Same as [`Matrix::neighbors`], along with the direction leading to each neighbor.
+    pub fn neighbors_with_direction<D: Direction>(
+        &self,
+        pos: Vec2<usize>,
+    ) -> impl Iterator<Item = (D, Vec2<usize>, &T)> {
+        D::all_clockwise().filter_map(move |direction| {
+            let next = direction.advance(Vec2::<isize>::from(pos));
+            let next = Vec2::<usize>::try_from(&next)
+                .ok()
+                .filter(|next| next.x < self.cols && next.y < self.rows)?;
+            Some((direction, next, &self[next]))
+        })
+    }
+
+    /// Bring `pos` back inside the matrix, as if the matrix was tiled infinitely in every direction.
+    pub fn wrap(&self, pos: &Vec2<isize>) -> Vec2<usize> {
+        Vec2::new(
+            pos.x.rem_euclid(self.cols as isize) as usize,
+            pos.y.rem_euclid(self.rows as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapping(&self, pos: &Vec2<isize>) -> &T {
+        &self[self.wrap(pos)]
+    }
+
+    /// Neighbors of `pos` on the infinitely tiled matrix, the positions are not wrapped but
+    /// the values are read from the wrapped positions.
+    pub fn neighbors_wrapping<D: Direction>(
+        &self,
+        pos: Vec2<isize>,
+    ) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        D::all_clockwise().map(move |direction| {
+            let next = direction.advance(pos);
+            (next, self.get_wrapping(&next))
+        })
+    }
+}
+
+impl<T: Clone> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(value: Vec<Vec<T>>) -> Self {
+        match Self::try_from_rows(value) {
+            Ok(matrix) => matrix,
+            Err(error) => panic!("Not from a matrix format: {error}"),
+        }
     }
 }
 
@@ -157,3 +382,149 @@ impl<T: Display> Display for Matrix<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        directions::{Cardinal, ExtendedCardinal},
+        vec2::Vec2,
+    };
+
+    use super::Matrix;
+
+    fn matrix() -> Matrix<u8> {
+        Matrix::from(vec![vec![1, 2, 3], vec![4, 5, 6]])
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let matrix = matrix();
+        let values = |pos| {
+            matrix
+                .neighbors::<Cardinal>(pos)
+                .map(|(_, &v)| v)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(vec![2, 4], values(Vec2::new(0, 0)));
+        assert_eq!(vec![3, 5, 1], values(Vec2::new(1, 0)));
+        let around = matrix
+            .neighbors::<ExtendedCardinal>(Vec2::new(1, 1))
+            .map(|(pos, &v)| (pos, v))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (Vec2::new(1, 0), 2),
+                (Vec2::new(2, 0), 3),
+                (Vec2::new(2, 1), 6),
+                (Vec2::new(0, 1), 4),
+                (Vec2::new(0, 0), 1),
+            ],
+            around
+        );
+        let (direction, pos, _) = matrix
+            .neighbors_with_direction::<Cardinal>(Vec2::new(2, 1))
+            .next()
+            .unwrap();
+        assert_eq!((Cardinal::North, Vec2::new(2, 0)), (direction, pos));
+    }
+
+    #[test]
+    fn test_neighbors_wrapping() {
+        let matrix = matrix();
+        assert_eq!(Vec2::new(2, 1), matrix.wrap(&Vec2::new(-1, -1)));
+        assert_eq!(&1, matrix.get_wrapping(&Vec2::new(6, 4)));
+        let around = matrix
+            .neighbors_wrapping::<Cardinal>(Vec2::new(0, 0))
+            .map(|(pos, &v)| (pos, v))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (Vec2::new(0, -1), 4),
+                (Vec2::new(1, 0), 2),
+                (Vec2::new(0, 1), 4),
+                (Vec2::new(-1, 0), 3),
+            ],
+            around
+        );
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let matrix = matrix();
+        assert_eq!(&[4, 5, 6], matrix.row(1));
+        assert_eq!(vec![&3, &6], matrix.column(2).collect::<Vec<_>>());
+        let columns = matrix
+            .iter_columns()
+            .map(|c| c.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], columns);
+        let diagonals = matrix
+            .diagonals()
+            .map(|d| d.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![vec![4], vec![1, 5], vec![2, 6], vec![3]], diagonals);
+        let anti_diagonals = matrix
+            .anti_diagonals()
+            .map(|d| d.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![vec![1], vec![2, 4], vec![3, 5], vec![6]],
+            anti_diagonals
+        );
+        let ray = matrix
+            .ray(Vec2::new(2, 1), Cardinal::West)
+            .map(|(_, &v)| v)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![6, 5, 4], ray);
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 out of a matrix of 3 columns")]
+    fn test_column_out_of_bounds() {
+        let _ = matrix().column(3);
+    }
+
+    #[test]
+    fn test_view() {
+        let matrix = Matrix::from_fn(4, 4, |pos| pos.y * 4 + pos.x);
+        let view = matrix.view(Vec2::new(1, 2), 2, 3).unwrap();
+        assert_eq!(9, view[Vec2::new(0, 0)]);
+        assert_eq!(Some(&15), view.get(Vec2::new(2, 1)));
+        assert_eq!(None, view.get(Vec2::new(3, 0)));
+        assert_eq!(
+            vec![&[9, 10, 11][..], &[13, 14, 15]],
+            view.iter_rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Matrix::from(vec![vec![9, 10, 11], vec![13, 14, 15]]),
+            view.to_matrix()
+        );
+        assert!(matrix.view(Vec2::new(2, 2), 2, 3).is_none());
+    }
+
+    #[test]
+    fn test_transformations() {
+        let matrix = matrix();
+        assert_eq!(
+            Matrix::from(vec![vec![1, 4], vec![2, 5], vec![3, 6]]),
+            matrix.transpose()
+        );
+        assert_eq!(
+            Matrix::from(vec![vec![4, 1], vec![5, 2], vec![6, 3]]),
+            matrix.rotate_cw()
+        );
+        assert_eq!(
+            Matrix::from(vec![vec![3, 6], vec![2, 5], vec![1, 4]]),
+            matrix.rotate_ccw()
+        );
+        assert_eq!(matrix, matrix.rotate_cw().rotate_ccw());
+        assert_eq!(
+            Matrix::from(vec![vec![3, 2, 1], vec![6, 5, 4]]),
+            matrix.flip_horizontal()
+        );
+        assert_eq!(
+            Matrix::from(vec![vec![4, 5, 6], vec![1, 2, 3]]),
+            matrix.flip_vertical()
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use super::{directions::Cardinal, matrix::Matrix, vec2::Vec2};
+
+/// A maze reduced to its junctions, the corridors between them becoming weighted edges.
+#[derive(Debug, Clone)]
+pub struct Junctions {
+    /// Position of each junction, indexed by id.
+    pub nodes: Vec<Vec2<usize>>,
+    /// Corridors going out of each junction, as the id of the junction they lead to and their
+    /// length in steps.
+    pub edges: Vec<Vec<(usize, usize)>>,
+    ids: HashMap<Vec2<usize>, usize>,
+}
+
+impl Junctions {
+    pub fn id(&self, pos: Vec2<usize>) -> Option<usize> {
+        self.ids.get(&pos).copied()
+    }
+
+    /// Length of the longest path from `from` to `to` never going twice through the same
+    /// junction, `None` if `to` cannot be reached. Visited junctions are tracked in a bitmask,
+    /// so there can be at most 128 of them.
+    pub fn longest_path(&self, from: usize, to: usize) -> Option<usize> {
+        assert!(
+            self.nodes.len() <= 128,
+            "too many junctions for a longest path search"
+        );
+        self.longest_from(from, to, 1 << from)
+    }
+
+    fn longest_from(&self, node: usize, to: usize, visited: u128) -> Option<usize> {
+        if node == to {
+            return Some(0);
+        }
+        self.edges[node]
+            .iter()
+            .filter(|&&(next, _)| visited & (1 << next) == 0)
+            .filter_map(|&(next, length)| {
+                self.longest_from(next, to, visited | (1 << next))
+                    .map(|rest| rest + length)
+            })
+            .max()
+    }
+}
+
+/// Junctions of the maze made of the `passable` cells: the cells with other than two passable
+/// neighbors, plus the `endpoints`.
+pub fn junctions<T>(
+    matrix: &Matrix<T>,
+    endpoints: &[Vec2<usize>],
+    passable: impl Fn(&T) -> bool,
+) -> Junctions {
+    junctions_directed(matrix, endpoints, &passable, |_, _, to| passable(to))
+}
+
+/// Like [`junctions`], corridors only being followed through steps allowed by `can_move`, given
+/// the cell left, the direction taken and the cell entered. This honors cells such as slopes
+/// which can only be crossed one way.
+pub fn junctions_directed<T>(
+    matrix: &Matrix<T>,
+    endpoints: &[Vec2<usize>],
+    passable: impl Fn(&T) -> bool,
+    can_move: impl Fn(&T, Cardinal, &T) -> bool,
+) -> Junctions {
+    let mut nodes = vec![];
+    let mut ids = HashMap::new();
+    for y in 0..matrix.rows {
+        for x in 0..matrix.cols {
+            let pos = Vec2::new(x, y);
+            if !passable(&matrix[pos]) {
+                continue;
+            }
+            let exits = matrix
+                .neighbors::<Cardinal>(pos)
+                .filter(|(_, tile)| passable(tile))
+                .count();
+            if exits != 2 || endpoints.contains(&pos) {
+                ids.insert(pos, nodes.len());
+                nodes.push(pos);
+            }
+        }
+    }
+
+    let mut edges = vec![vec![]; nodes.len()];
+    for (id, &start) in nodes.iter().enumerate() {
+        for (direction, first, _) in matrix.neighbors_with_direction::<Cardinal>(start) {
+            if !passable(&matrix[first]) || !can_move(&matrix[start], direction, &matrix[first]) {
+                continue;
+            }
+            // follow the corridor, which only has one way forward, up to the next junction
+            let (mut previous, mut current, mut length) = (start, first, 1);
+            let end = loop {
+                if let Some(&end) = ids.get(&current) {
+                    break Some(end);
+                }
+                let forward = matrix
+                    .neighbors_with_direction::<Cardinal>(current)
+                    .find(|&(_, next, tile)| next != previous && passable(tile));
+                match forward {
+                    Some((direction, next, tile))
+                        if can_move(&matrix[current], direction, tile) =>
+                    {
+                        (previous, current) = (current, next);
+                        length += 1;
+                    }
+                    _ => break None,
+                }
+            };
+            if let Some(end) = end {
+                edges[id].push((end, length));
+            }
+        }
+    }
+    Junctions { nodes, edges, ids }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{directions::Cardinal, matrix::Matrix, vec2::Vec2};
+
+    use super::{junctions, junctions_directed};
+
+    fn maze() -> Matrix<char> {
+        let input = [
+            "#.#####", "#.....#", "#.###.#", "#.....#", "###v###", "###.###",
+        ]
+        .map(String::from);
+        Matrix::from_chars(&input)
+    }
+
+    #[test]
+    fn test_junctions() {
+        let maze = maze();
+        let (start, goal) = (Vec2::new(1, 0), Vec2::new(3, 5));
+        let junctions = junctions(&maze, &[start, goal], |&c| c != '#');
+        assert_eq!(
+            vec![start, Vec2::new(1, 1), Vec2::new(3, 3), goal],
+            junctions.nodes
+        );
+        let (a, b) = (junctions.id(Vec2::new(1, 1)).unwrap(), 2);
+        assert_eq!(vec![(0, 1), (2, 8), (2, 4)], junctions.edges[a]);
+        assert_eq!(vec![(a, 8), (3, 2), (a, 4)], junctions.edges[b]);
+        assert_eq!(Some(11), junctions.longest_path(0, 3));
+    }
+
+    #[test]
+    fn test_directed() {
+        let maze = maze();
+        let (start, goal) = (Vec2::new(1, 0), Vec2::new(3, 5));
+        // the slope can only be crossed to the south
+        let junctions = junctions_directed(
+            &maze,
+            &[start, goal],
+            |&c| c != '#',
+            |from, direction, _| *from != 'v' || direction == Cardinal::South,
+        );
+        assert_eq!(vec![(1, 8), (3, 2), (1, 4)], junctions.edges[2]);
+        assert!(junctions.edges[3].is_empty());
+        assert_eq!(Some(11), junctions.longest_path(0, 3));
+        assert_eq!(None, junctions.longest_path(3, 0));
+    }
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Where a [`Memo`] keeps its values.
+pub trait Store<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn insert(&mut self, key: K, value: V);
+}
+
+impl<K: Hash + Eq, V> Store<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        HashMap::insert(self, key, value);
+    }
+}
+
+/// Array backed store for keys mapping to small integers, much faster than hashing.
+pub struct Dense<K, V> {
+    values: Vec<Option<V>>,
+    index: fn(&K) -> usize,
+}
+
+impl<K, V> Store<K, V> for Dense<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        self.values[(self.index)(key)].as_ref()
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        self.values[(self.index)(&key)] = Some(value);
+    }
+}
+
+/// Cache for a recursive function, keyed on its varying arguments. The function takes the memo
+/// as argument and wraps its body in [`Memo::get_or_compute`], passing the memo it gets back to
+/// its recursive calls.
+pub struct Memo<K, V, S = HashMap<K, V>> {
+    store: S,
+    hits: usize,
+    misses: usize,
+    marker: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::with_store(HashMap::new())
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Memo<K, V, Dense<K, V>> {
+    /// Memo whose keys are mapped by `index` to `0..size`.
+    pub fn dense(size: usize, index: fn(&K) -> usize) -> Self {
+        let values = std::iter::repeat_with(|| None).take(size).collect();
+        Self::with_store(Dense { values, index })
+    }
+}
+
+impl<K, V, S: Store<K, V>> Memo<K, V, S> {
+    pub fn with_store(store: S) -> Self {
+        Self {
+            store,
+            hits: 0,
+            misses: 0,
+            marker: std::marker::PhantomData,
+        }
+    }
+
+    /// The cached value of `key`, or the one computed by `compute`, which gets the memo back for
+    /// the recursive calls.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V
+    where
+        V: Clone,
+    {
+        if let Some(value) = self.store.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.store.insert(key, value.clone());
+        value
+    }
+
+    /// Number of calls answered from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of calls that had to compute their value.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Dense, Memo};
+
+    fn paths(memo: &mut Memo<(usize, usize), u64>, x: usize, y: usize) -> u64 {
+        memo.get_or_compute((x, y), |memo| {
+            if x == 0 || y == 0 {
+                1
+            } else {
+                paths(memo, x - 1, y) + paths(memo, x, y - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut memo = Memo::new();
+        assert_eq!(184756, paths(&mut memo, 10, 10));
+        assert_eq!((120, 81), (memo.misses(), memo.hits()));
+        assert_eq!(184756, paths(&mut memo, 10, 10));
+        assert_eq!((120, 82), (memo.misses(), memo.hits()));
+    }
+
+    #[test]
+    fn test_dense() {
+        fn stairs(memo: &mut Memo<usize, u64, Dense<usize, u64>>, n: usize) -> u64 {
+            memo.get_or_compute(n, |memo| {
+                // ways to climb `n` stairs by steps of one or two
+                if n < 2 {
+                    1
+                } else {
+                    stairs(memo, n - 1) + stairs(memo, n - 2)
+                }
+            })
+        }
+        let mut memo = Memo::dense(91, |&n| n);
+        assert_eq!(4660046610375530309, stairs(&mut memo, 90));
+        assert_eq!((91, 88), (memo.misses(), memo.hits()));
+    }
+}
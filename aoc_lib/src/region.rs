@@ -0,0 +1,204 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{
+    directions::{Advance, Cardinal, Direction},
+    matrix::Matrix,
+    vec2::Vec2,
+};
+
+/// A connected group of cells of a [`Matrix`], along with its geometry.
+#[derive(Debug, Clone)]
+pub struct Region {
+    pub cells: Vec<Vec2<usize>>,
+    /// Number of cell edges shared with the outside of the region.
+    pub perimeter: usize,
+    /// Number of straight sides of the fence around the region, holes included. This is also its
+    /// number of corners.
+    pub sides: usize,
+    /// Inclusive corners of the bounding box.
+    pub min: Vec2<usize>,
+    pub max: Vec2<usize>,
+    pub touches_border: bool,
+}
+
+impl Region {
+    fn new<T>(matrix: &Matrix<T>, cells: Vec<Vec2<usize>>) -> Self {
+        let members = cells
+            .iter()
+            .map(|&p| Vec2::<isize>::from(p))
+            .collect::<HashSet<_>>();
+        let mut perimeter = 0;
+        let mut sides = 0;
+        for &cell in &members {
+            for direction in Cardinal::all_clockwise() {
+                let front = members.contains(&direction.advance(cell));
+                let right = members.contains(&direction.turn_right().advance(cell));
+                let diagonal =
+                    members.contains(&direction.turn_right().advance(direction.advance(cell)));
+                if !front {
+                    perimeter += 1;
+                }
+                // outer corner when both sides are missing, inner corner when only the
+                // diagonal is missing
+                if (!front && !right) || (front && right && !diagonal) {
+                    sides += 1;
+                }
+            }
+        }
+        let min = Vec2::new(
+            cells.iter().map(|p| p.x).min().unwrap_or(0),
+            cells.iter().map(|p| p.y).min().unwrap_or(0),
+        );
+        let max = Vec2::new(
+            cells.iter().map(|p| p.x).max().unwrap_or(0),
+            cells.iter().map(|p| p.y).max().unwrap_or(0),
+        );
+        let touches_border = !cells.is_empty()
+            && (min.x == 0 || min.y == 0 || max.x + 1 == matrix.cols || max.y + 1 == matrix.rows);
+        Self {
+            cells,
+            perimeter,
+            sides,
+            min,
+            max,
+            touches_border,
+        }
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Connected components of a [`Matrix`].
+#[derive(Debug, Clone)]
+pub struct Regions {
+    /// Index in `regions` of the region of each cell, `None` for the cells left out.
+    pub labels: Matrix<Option<usize>>,
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    pub fn region_of(&self, pos: Vec2<usize>) -> Option<&Region> {
+        self.labels[pos].map(|label| &self.regions[label])
+    }
+}
+
+/// Cells reachable from `start` through cardinal steps between `connected` values, in breadth
+/// first order.
+pub fn flood_fill<T>(
+    matrix: &Matrix<T>,
+    start: Vec2<usize>,
+    mut connected: impl FnMut(&T, &T) -> bool,
+) -> Vec<Vec2<usize>> {
+    let mut visited = Matrix::new(matrix.rows, matrix.cols, false);
+    fill(matrix, start, &mut visited, &mut connected)
+}
+
+/// Split the whole matrix into regions of cells connected under `connected`, typically equality.
+pub fn label<T>(matrix: &Matrix<T>, connected: impl FnMut(&T, &T) -> bool) -> Regions {
+    label_by(matrix, |_| true, connected)
+}
+
+/// Regions made of the adjacent cells matching `predicate`, other cells are left out.
+pub fn label_where<T>(matrix: &Matrix<T>, mut predicate: impl FnMut(&T) -> bool) -> Regions {
+    let included = Matrix::from_fn(matrix.rows, matrix.cols, |pos| predicate(&matrix[pos]));
+    label_by(&included, |&included| included, |a, b| *a && *b)
+}
+
+fn label_by<T>(
+    matrix: &Matrix<T>,
+    mut include: impl FnMut(&T) -> bool,
+    mut connected: impl FnMut(&T, &T) -> bool,
+) -> Regions {
+    let mut labels = Matrix::new(matrix.rows, matrix.cols, None);
+    let mut visited = Matrix::new(matrix.rows, matrix.cols, false);
+    let mut regions = vec![];
+    for y in 0..matrix.rows {
+        for x in 0..matrix.cols {
+            let pos = Vec2::new(x, y);
+            if visited[pos] || !include(&matrix[pos]) {
+                continue;
+            }
+            let cells = fill(matrix, pos, &mut visited, &mut connected);
+            for &cell in &cells {
+                labels[cell] = Some(regions.len());
+            }
+            regions.push(Region::new(matrix, cells));
+        }
+    }
+    Regions { labels, regions }
+}
+
+fn fill<T>(
+    matrix: &Matrix<T>,
+    start: Vec2<usize>,
+    visited: &mut Matrix<bool>,
+    connected: &mut impl FnMut(&T, &T) -> bool,
+) -> Vec<Vec2<usize>> {
+    let mut cells = vec![];
+    let mut queue = VecDeque::from([start]);
+    visited[start] = true;
+    while let Some(pos) = queue.pop_front() {
+        cells.push(pos);
+        for (next, value) in matrix.neighbors::<Cardinal>(pos) {
+            if !visited[next] && connected(&matrix[pos], value) {
+                visited[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    cells
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{matrix::Matrix, vec2::Vec2};
+
+    use super::{flood_fill, label, label_where};
+
+    fn garden() -> Matrix<char> {
+        let input = ["AAAA", "BBCD", "BBCC", "EEEC"].map(String::from);
+        Matrix::from_chars(&input)
+    }
+
+    #[test]
+    fn test_label() {
+        let regions = label(&garden(), |a, b| a == b);
+        assert_eq!(5, regions.regions.len());
+        let summary = regions
+            .regions
+            .iter()
+            .map(|r| (r.area(), r.perimeter, r.sides))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)],
+            summary
+        );
+        let c = regions.region_of(Vec2::new(2, 1)).unwrap();
+        assert_eq!((Vec2::new(2, 1), Vec2::new(3, 3)), (c.min, c.max));
+        assert!(c.touches_border);
+    }
+
+    #[test]
+    fn test_holes() {
+        let input = ["OOOOO", "OXOXO", "OOOOO", "OXOXO", "OOOOO"].map(String::from);
+        let regions = label(&Matrix::from_chars(&input), |a, b| a == b);
+        let outer = &regions.regions[0];
+        assert_eq!((21, 36, 20), (outer.area(), outer.perimeter, outer.sides));
+        let hole = regions.region_of(Vec2::new(1, 1)).unwrap();
+        assert!(!hole.touches_border);
+    }
+
+    #[test]
+    fn test_label_where_and_flood_fill() {
+        let garden = garden();
+        let regions = label_where(&garden, |&c| c == 'B' || c == 'E');
+        assert_eq!(1, regions.regions.len());
+        assert_eq!(7, regions.regions[0].area());
+        assert_eq!(None, regions.labels[Vec2::new(0_usize, 0)]);
+        let filled = flood_fill(&garden, Vec2::new(3, 3), |a, b| a == b);
+        assert_eq!(4, filled.len());
+        assert_eq!(Vec2::new(3, 3), filled[0]);
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use super::solution::DynSolution;
+
+/// A solution registered for a given `year` and `day`.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u16,
+    pub title: &'static str,
+    pub solution: &'static dyn DynSolution,
+}
+
+impl Entry {
+    pub const fn new(
+        year: u16,
+        day: u16,
+        title: &'static str,
+        solution: &'static dyn DynSolution,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            title,
+            solution,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum RegistryError {
+    YearNotAvailable(u16),
+    DayNotAvailable { year: u16, day: u16 },
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::YearNotAvailable(year) => {
+                write!(f, "no solutions are available for year {year}")
+            }
+            RegistryError::DayNotAvailable { year, day } => {
+                write!(f, "no solution is available for day {day} of year {year}")
+            }
+        }
+    }
+}
+
+impl Error for RegistryError {}
+
+/// Every known solution, looked up by `(year, day)` rather than by position.
+#[derive(Default)]
+pub struct Registry {
+    entries: Vec<Entry>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, entries: &[Entry]) -> Self {
+        self.register(entries);
+        self
+    }
+
+    pub fn register(&mut self, entries: &[Entry]) {
+        for entry in entries {
+            if let Some(existing) = self
+                .entries
+                .iter_mut()
+                .find(|e| e.year == entry.year && e.day == entry.day)
+            {
+                *existing = *entry;
+            } else {
+                self.entries.push(*entry);
+            }
+        }
+        self.entries.sort_by_key(|e| (e.year, e.day));
+    }
+
+    pub fn get(&self, year: u16, day: u16) -> Result<&Entry, RegistryError> {
+        self.year(year)?
+            .find(|e| e.day == day)
+            .ok_or(RegistryError::DayNotAvailable { year, day })
+    }
+
+    pub fn year(&self, year: u16) -> Result<impl Iterator<Item = &Entry>, RegistryError> {
+        if !self.entries.iter().any(|e| e.year == year) {
+            return Err(RegistryError::YearNotAvailable(year));
+        }
+        Ok(self.entries.iter().filter(move |e| e.year == year))
+    }
+
+    pub fn years(&self) -> Vec<u16> {
+        let mut years = self.entries.iter().map(|e| e.year).collect::<Vec<_>>();
+        years.dedup();
+        years
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{answer::Answer, solution::Solution};
+
+    use super::{Entry, Registry, RegistryError};
+
+    struct Dummy;
+
+    impl Solution for Dummy {
+        fn part_a(&self, _input: &[String]) -> Answer {
+            Answer::Unimplemented
+        }
+
+        fn part_b(&self, _input: &[String]) -> Answer {
+            Answer::Unimplemented
+        }
+    }
+
+    const ENTRIES: &[Entry] = &[
+        Entry::new(2023, 1, "First", &Dummy),
+        Entry::new(2023, 3, "Third", &Dummy),
+    ];
+
+    #[test]
+    fn test_lookup() {
+        let registry = Registry::new().with(ENTRIES);
+        assert_eq!("Third", registry.get(2023, 3).unwrap().title);
+        assert_eq!(
+            Some(RegistryError::DayNotAvailable { year: 2023, day: 2 }),
+            registry.get(2023, 2).err()
+        );
+        assert_eq!(
+            Some(RegistryError::YearNotAvailable(2024)),
+            registry.get(2024, 1).err()
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use num::Zero;
+
+/// Outcome of a successful search: the cost of the shortest paths to the goal, and enough of the
+/// explored graph to rebuild them.
+#[derive(Debug, Clone)]
+pub struct Shortest<S, C> {
+    pub cost: C,
+    goals: Vec<S>,
+    // best known cost of each reached state, and every predecessor reaching it at that cost
+    parents: HashMap<S, (C, Vec<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C> Shortest<S, C> {
+    /// The first goal state that was reached.
+    pub fn goal(&self) -> &S {
+        &self.goals[0]
+    }
+
+    /// Every goal state reached at the shortest cost.
+    pub fn goals(&self) -> &[S] {
+        &self.goals
+    }
+
+    /// One shortest path, from the start state to the goal state included.
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec![self.goal().clone()];
+        while let Some(previous) = self.parents[path.last().unwrap()].1.first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        path
+    }
+
+    /// Every state lying on at least one shortest path to a goal.
+    pub fn on_shortest_paths(&self) -> HashSet<S> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+        while let Some(state) = stack.pop() {
+            if seen.insert(state.clone()) {
+                stack.extend(self.parents[&state].1.iter().cloned());
+            }
+        }
+        seen
+    }
+}
+
+/// Breadth first search, every step costing one.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Shortest<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut parents = HashMap::from([(start.clone(), (0, vec![]))]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut best = None;
+    let mut goals = vec![];
+    while let Some((state, cost)) = queue.pop_front() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for next in successors(&state) {
+            match parents.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((cost + 1, vec![state.clone()]));
+                    queue.push_back((next, cost + 1));
+                }
+                Entry::Occupied(mut entry) => {
+                    let (known, previous) = entry.get_mut();
+                    if *known == cost + 1 {
+                        previous.push(state.clone());
+                    }
+                }
+            }
+        }
+    }
+    Some(Shortest {
+        cost: best?,
+        goals,
+        parents,
+    })
+}
+
+/// Dijkstra's algorithm, `successors` giving the next states along with the cost to reach them.
+/// Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Shortest<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search, `heuristic` estimating the remaining cost to a goal. The heuristic must never
+/// overestimate for the cost to be the shortest, and must be consistent for
+/// [`Shortest::on_shortest_paths`] to be complete.
+pub fn astar<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Shortest<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut parents = HashMap::from([(start.clone(), (C::zero(), vec![]))]);
+    let mut closed = HashSet::new();
+    let mut heap = BinaryHeap::from([Queued {
+        estimate: heuristic(&start),
+        cost: C::zero(),
+        state: start,
+    }]);
+    let mut best = None;
+    let mut goals = vec![];
+    while let Some(Queued {
+        estimate,
+        cost,
+        state,
+    }) = heap.pop()
+    {
+        if best.is_some_and(|best| estimate > best) {
+            break;
+        }
+        if cost > parents[&state].0 || !closed.insert(state.clone()) {
+            continue;
+        }
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            match parents.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((next_cost, vec![state.clone()]));
+                }
+                Entry::Occupied(mut entry) => {
+                    let (known, previous) = entry.get_mut();
+                    match next_cost.cmp(known) {
+                        Ordering::Less => {
+                            *known = next_cost;
+                            *previous = vec![state.clone()];
+                        }
+                        // the start state, the only one without predecessors, keeps none even
+                        // when a zero cost cycle leads back to it
+                        Ordering::Equal if previous.is_empty() => continue,
+                        Ordering::Equal => {
+                            previous.push(state.clone());
+                            continue;
+                        }
+                        Ordering::Greater => continue,
+                    }
+                }
+            }
+            heap.push(Queued {
+                estimate: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+    Some(Shortest {
+        cost: best?,
+        goals,
+        parents,
+    })
+}
+
+struct Queued<S, C> {
+    estimate: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{astar, bfs, dijkstra};
+
+    const GRID: [&str; 5] = ["S..#.", ".#.#.", ".#...", ".##.#", "....E"];
+
+    fn neighbors((x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| {
+                let (x, y) = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+                (GRID.get(y)?.as_bytes().get(x)? != &b'#').then_some((x, y))
+            })
+    }
+
+    #[test]
+    fn test_bfs() {
+        let shortest = bfs((0, 0), |&pos| neighbors(pos), |&pos| pos == (4, 4)).unwrap();
+        assert_eq!(8, shortest.cost);
+        let path = shortest.path();
+        assert_eq!(9, path.len());
+        assert_eq!((0, 0), path[0]);
+        assert_eq!((4, 4), path[8]);
+        // both ways around the walls are shortest
+        assert_eq!(15, shortest.on_shortest_paths().len());
+        assert!(bfs((0, 0), |&pos| neighbors(pos), |_| false).is_none());
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = [
+            (0, 1, 4),
+            (0, 2, 1),
+            (2, 1, 2),
+            (1, 3, 1),
+            (2, 3, 5),
+            (3, 4, 3),
+        ];
+        let successors = |&node: &u32| {
+            edges
+                .iter()
+                .filter(move |(from, _, _)| *from == node)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+        let shortest = dijkstra(0, successors, |&node| node == 4).unwrap();
+        assert_eq!(7, shortest.cost);
+        assert_eq!(vec![0, 2, 1, 3, 4], shortest.path());
+        assert_eq!(HashSet::from([0, 1, 2, 3, 4]), shortest.on_shortest_paths());
+        let shortest = dijkstra(0, successors, |&node| node == 3).unwrap();
+        assert_eq!(4, shortest.cost);
+        assert_eq!(HashSet::from([0, 1, 2, 3]), shortest.on_shortest_paths());
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        // 0 and 1 can be swapped for free, as can 2 and 3
+        let edges = [(0, 1, 0), (1, 0, 0), (1, 2, 5), (2, 3, 0), (3, 2, 0)];
+        let successors = |&node: &u32| {
+            edges
+                .iter()
+                .filter(move |(from, _, _)| *from == node)
+                .map(|&(_, to, cost)| (to, cost))
+        };
+        let shortest = dijkstra(0, successors, |&node| node == 3).unwrap();
+        assert_eq!(5, shortest.cost);
+        assert_eq!(vec![0, 1, 2, 3], shortest.path());
+        assert_eq!(HashSet::from([0, 1, 2, 3]), shortest.on_shortest_paths());
+    }
+
+    #[test]
+    fn test_astar() {
+        let manhattan = |&(x, y): &(usize, usize)| 8 - x - y;
+        let shortest = astar(
+            (0, 0),
+            |&pos| neighbors(pos).map(|next| (next, 1)),
+            manhattan,
+            |&pos| pos == (4, 4),
+        )
+        .unwrap();
+        assert_eq!(8, shortest.cost);
+        assert_eq!(15, shortest.on_shortest_paths().len());
+    }
+}
@@ -1,6 +1,93 @@
-use super::answer::Answer;
+use std::any::Any;
+
+use super::{answer::Answer, error::AocError};
 
 pub trait Solution {
     fn part_a(&self, input: &[String]) -> Answer;
     fn part_b(&self, input: &[String]) -> Answer;
 }
+
+/// A solution that parses its input once, both parts borrowing the parsed value.
+pub trait ParsedSolution {
+    type Parsed;
+
+    fn parse(&self, input: &[String]) -> Self::Parsed;
+    fn part_a(&self, parsed: &Self::Parsed) -> Answer;
+    fn part_b(&self, parsed: &Self::Parsed) -> Answer;
+}
+
+/// A [`ParsedSolution`] reporting malformed input as an [`AocError`] instead of panicking.
+pub trait FallibleSolution {
+    type Parsed;
+
+    fn parse(&self, input: &[String]) -> Result<Self::Parsed, AocError>;
+    fn part_a(&self, parsed: &Self::Parsed) -> Result<Answer, AocError>;
+    fn part_b(&self, parsed: &Self::Parsed) -> Result<Answer, AocError>;
+}
+
+// Days that are not migrated yet parse inside their parts, their parsed value is the raw input.
+impl<S: Solution> ParsedSolution for S {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &[String]) -> Self::Parsed {
+        input.to_vec()
+    }
+
+    fn part_a(&self, parsed: &Self::Parsed) -> Answer {
+        Solution::part_a(self, parsed)
+    }
+
+    fn part_b(&self, parsed: &Self::Parsed) -> Answer {
+        Solution::part_b(self, parsed)
+    }
+}
+
+impl<S: ParsedSolution> FallibleSolution for S {
+    type Parsed = S::Parsed;
+
+    fn parse(&self, input: &[String]) -> Result<Self::Parsed, AocError> {
+        Ok(ParsedSolution::parse(self, input))
+    }
+
+    fn part_a(&self, parsed: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(ParsedSolution::part_a(self, parsed))
+    }
+
+    fn part_b(&self, parsed: &Self::Parsed) -> Result<Answer, AocError> {
+        Ok(ParsedSolution::part_b(self, parsed))
+    }
+}
+
+/// Object safe form of [`FallibleSolution`], with a type-erased parsed value, used by the registry.
+pub trait DynSolution {
+    fn parse_dyn(&self, input: &[String]) -> Result<Box<dyn Any>, AocError>;
+    fn part_a_dyn(&self, parsed: &dyn Any) -> Result<Answer, AocError>;
+    fn part_b_dyn(&self, parsed: &dyn Any) -> Result<Answer, AocError>;
+}
+
+impl<S> DynSolution for S
+where
+    S: FallibleSolution,
+    S::Parsed: 'static,
+{
+    fn parse_dyn(&self, input: &[String]) -> Result<Box<dyn Any>, AocError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn part_a_dyn(&self, parsed: &dyn Any) -> Result<Answer, AocError> {
+        self.part_a(downcast::<S>(parsed))
+    }
+
+    fn part_b_dyn(&self, parsed: &dyn Any) -> Result<Answer, AocError> {
+        self.part_b(downcast::<S>(parsed))
+    }
+}
+
+fn downcast<S: FallibleSolution>(parsed: &dyn Any) -> &S::Parsed
+where
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed value was not produced by this solution")
+}
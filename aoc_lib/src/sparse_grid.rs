@@ -0,0 +1,239 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use super::{
+    directions::{Advance, Direction},
+    matrix::Matrix,
+    vec2::Vec2,
+};
+
+/// Unbounded grid only storing its occupied cells, for puzzles whose coordinates can grow in
+/// every direction or go negative.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2<isize>, T>,
+    // inclusive corners of the bounding box of the cells
+    bounds: Option<(Vec2<isize>, Vec2<isize>)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: &Vec2<isize>) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn get_mut(&mut self, pos: &Vec2<isize>) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    pub fn contains(&self, pos: &Vec2<isize>) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    /// Set the cell at `pos`, returning its previous value.
+    pub fn insert(&mut self, pos: Vec2<isize>, value: T) -> Option<T> {
+        self.bounds = Some(expand(self.bounds, pos));
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: &Vec2<isize>) -> Option<T> {
+        let value = self.cells.remove(pos)?;
+        // the bounding box only shrinks when a cell of its border is removed
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                self.bounds = self.compute_bounds();
+            }
+        }
+        Some(value)
+    }
+
+    /// Inclusive top left and bottom right corners of the occupied cells.
+    pub fn bounds(&self) -> Option<(Vec2<isize>, Vec2<isize>)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Vec2<isize>, &T)> {
+        self.cells.iter()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &Vec2<isize>> {
+        self.cells.keys()
+    }
+
+    /// Positions and values of the occupied neighbors of `pos`, in the clockwise order of `D`.
+    pub fn neighbors<D: Direction>(
+        &self,
+        pos: Vec2<isize>,
+    ) -> impl Iterator<Item = (Vec2<isize>, &T)> {
+        self.neighbors_with_direction::<D>(pos)
+            .map(|(_, pos, value)| (pos, value))
+    }
+
+    /// Same as [`SparseGrid::neighbors`], along with the direction leading to each neighbor.
+    pub fn neighbors_with_direction<D: Direction>(
+        &self,
+        pos: Vec2<isize>,
+    ) -> impl Iterator<Item = (D, Vec2<isize>, &T)> {
+        D::all_clockwise().filter_map(move |direction| {
+            let next = direction.advance(pos);
+            self.get(&next).map(|value| (direction, next, value))
+        })
+    }
+
+    fn compute_bounds(&self) -> Option<(Vec2<isize>, Vec2<isize>)> {
+        self.cells
+            .keys()
+            .fold(None, |bounds, &pos| Some(expand(bounds, pos)))
+    }
+}
+
+fn expand(
+    bounds: Option<(Vec2<isize>, Vec2<isize>)>,
+    pos: Vec2<isize>,
+) -> (Vec2<isize>, Vec2<isize>) {
+    match bounds {
+        None => (pos, pos),
+        Some((min, max)) => (
+            Vec2::new(min.x.min(pos.x), min.y.min(pos.y)),
+            Vec2::new(max.x.max(pos.x), max.y.max(pos.y)),
+        ),
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Dense copy of the bounding box, `empty` filling the unoccupied cells. The top left corner
+    /// of the matrix is the top left corner of [`SparseGrid::bounds`].
+    pub fn to_matrix(&self, empty: T) -> Matrix<T> {
+        let Some((min, max)) = self.bounds else {
+            return Matrix::new(0, 0, empty);
+        };
+        let rows = (max.y - min.y + 1) as usize;
+        let cols = (max.x - min.x + 1) as usize;
+        Matrix::from_fn(rows, cols, |pos| {
+            let pos = min + Vec2::<isize>::from(pos);
+            self.get(&pos).unwrap_or(&empty).clone()
+        })
+    }
+}
+
+impl<T> FromIterator<(Vec2<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2<isize>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Vec2<isize>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Vec2<isize>, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+/// Render the bounding box, unoccupied cells being shown as `.`.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(&Vec2::new(x, y)) {
+                    Some(value) => write!(f, "{value}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        directions::{Cardinal, ExtendedCardinal},
+        matrix::Matrix,
+        vec2::Vec2,
+    };
+
+    use super::SparseGrid;
+
+    fn grid() -> SparseGrid<char> {
+        [
+            ((-1, -2), '#'),
+            ((1, 0), '#'),
+            ((0, 0), 'S'),
+            ((1, -1), '#'),
+        ]
+        .into_iter()
+        .map(|((x, y), c)| (Vec2::new(x, y), c))
+        .collect()
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = grid();
+        assert_eq!(Some((Vec2::new(-1, -2), Vec2::new(1, 0))), grid.bounds());
+        assert_eq!(Some('#'), grid.remove(&Vec2::new(-1, -2)));
+        assert_eq!(Some((Vec2::new(0, -1), Vec2::new(1, 0))), grid.bounds());
+        assert_eq!(None, grid.remove(&Vec2::new(-1, -2)));
+        grid.insert(Vec2::new(5, 3), '#');
+        assert_eq!(Some((Vec2::new(0, -1), Vec2::new(5, 3))), grid.bounds());
+        assert_eq!(None, SparseGrid::<char>::new().bounds());
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        let around = grid
+            .neighbors::<Cardinal>(Vec2::new(0, 0))
+            .map(|(pos, &c)| (pos, c))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(Vec2::new(1, 0), '#')], around);
+        assert_eq!(
+            2,
+            grid.neighbors::<ExtendedCardinal>(Vec2::new(0, 0)).count()
+        );
+    }
+
+    #[test]
+    fn test_to_matrix_and_display() {
+        let grid = grid();
+        let matrix = grid.to_matrix('.');
+        assert_eq!(
+            Matrix::from(vec![
+                vec!['#', '.', '.'],
+                vec!['.', '.', '#'],
+                vec!['.', 'S', '#'],
+            ]),
+            matrix
+        );
+        assert_eq!(matrix.to_string(), grid.to_string());
+        assert_eq!("#..\n..#\n.S#\n", grid.to_string());
+    }
+}
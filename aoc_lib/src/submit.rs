@@ -0,0 +1,173 @@
+use super::fetch::{FetchError, HttpBackend, DEFAULT_BASE_URL};
+
+/// How the puzzle website judged a submitted answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// Submitted too recently, the number of seconds left to wait.
+    Wait(u64),
+    /// The part is already solved, or is not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+/// Read the verdict out of the HTML page answered to a submission.
+pub fn parse_response(body: &str) -> Verdict {
+    if body.contains("That's the right answer") {
+        Verdict::Correct
+    } else if body.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if body.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if body.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else if body.contains("You gave an answer too recently") {
+        Verdict::Wait(parse_wait(body).unwrap_or(60))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        let text = body
+            .split_once("<article>")
+            .map_or(body, |(_, article)| article);
+        Verdict::Unknown(text.chars().take(200).collect())
+    }
+}
+
+// e.g. "You have 4m 25s left to wait."
+fn parse_wait(body: &str) -> Option<u64> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (duration, _) = rest.split_once(" left")?;
+    duration.split_whitespace().try_fold(0, |total, token| {
+        let (value, unit) = token.split_at(token.len().checked_sub(1)?);
+        let value = value.parse::<u64>().ok()?;
+        match unit {
+            "h" => Some(total + value * 3600),
+            "m" => Some(total + value * 60),
+            "s" => Some(total + value),
+            _ => None,
+        }
+    })
+}
+
+pub struct Submitter<B> {
+    backend: B,
+    base_url: String,
+    session: String,
+}
+
+impl<B: HttpBackend> Submitter<B> {
+    pub fn new(backend: B, session: impl Into<String>) -> Self {
+        Self {
+            backend,
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            session: session.into(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    pub fn answer_url(&self, year: u16, day: u16) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// Post `answer` for `level`, 1 being part A and 2 part B.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u16,
+        level: u8,
+        answer: &str,
+    ) -> Result<Verdict, FetchError> {
+        let level = level.to_string();
+        let form = [("level", level.as_str()), ("answer", answer)];
+        let body = self
+            .backend
+            .post_form(&self.answer_url(year, day), &self.session, &form)?;
+        Ok(parse_response(&body))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::cell::RefCell;
+
+    use crate::fetch::{FetchError, HttpBackend};
+
+    use super::{parse_response, Submitter, Verdict};
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            Verdict::Correct,
+            parse_response("<article><p>That's the right answer! You are one gold star closer.")
+        );
+        assert_eq!(
+            Verdict::TooHigh,
+            parse_response("That's not the right answer; your answer is too high.")
+        );
+        assert_eq!(
+            Verdict::TooLow,
+            parse_response("That's not the right answer; your answer is too low.")
+        );
+        assert_eq!(
+            Verdict::Incorrect,
+            parse_response("That's not the right answer. If you're stuck, ...")
+        );
+        assert_eq!(
+            Verdict::Wait(265),
+            parse_response("You gave an answer too recently; ... You have 4m 25s left to wait.")
+        );
+        assert_eq!(
+            Verdict::Wait(38),
+            parse_response("You gave an answer too recently; ... You have 38s left to wait.")
+        );
+        assert_eq!(
+            Verdict::WrongLevel,
+            parse_response(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )
+        );
+    }
+
+    struct Stub {
+        forms: RefCell<Vec<String>>,
+    }
+
+    impl HttpBackend for Stub {
+        fn get(&self, _url: &str, _session: &str) -> Result<String, FetchError> {
+            unreachable!()
+        }
+
+        fn post_form(
+            &self,
+            url: &str,
+            _session: &str,
+            form: &[(&str, &str)],
+        ) -> Result<String, FetchError> {
+            self.forms.borrow_mut().push(format!("{url} {form:?}"));
+            Ok("That's the right answer!".to_owned())
+        }
+    }
+
+    #[test]
+    fn test_submit() {
+        let stub = Stub {
+            forms: RefCell::new(vec![]),
+        };
+        let submitter = Submitter::new(stub, "abc").with_base_url("http://localhost:8080");
+        assert_eq!(
+            Verdict::Correct,
+            submitter.submit(2024, 3, 2, "42").unwrap()
+        );
+        assert_eq!(
+            vec![r#"http://localhost:8080/2024/day/3/answer [("level", "2"), ("answer", "42")]"#],
+            *submitter.backend.forms.borrow()
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// Disjoint sets over the ids `0..len`, with path compression and union by size.
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl UnionFind {
+    /// `len` singletons.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Add a new singleton, returning its id.
+    pub fn push(&mut self) -> usize {
+        let id = self.parent.len();
+        self.parent.push(id);
+        self.size.push(1);
+        self.sets += 1;
+        id
+    }
+
+    /// Representative of the set of `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut current = x;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        root
+    }
+
+    /// Merge the sets of `a` and `b`, false if they were already the same.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut root_a, mut root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return false;
+        }
+        if self.size[root_a] < self.size[root_b] {
+            std::mem::swap(&mut root_a, &mut root_b);
+        }
+        self.parent[root_b] = root_a;
+        self.size[root_a] += self.size[root_b];
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Size of the set of `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn sets(&self) -> usize {
+        self.sets
+    }
+
+    /// Size of every set, in no particular order.
+    pub fn sizes(&self) -> Vec<usize> {
+        (0..self.len())
+            .filter(|&x| self.parent[x] == x)
+            .map(|root| self.size[root])
+            .collect()
+    }
+
+    /// Members of every set, sets being ordered by their smallest member.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut index = HashMap::new();
+        let mut components: Vec<Vec<usize>> = vec![];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            let i = *index.entry(root).or_insert_with(|| {
+                components.push(vec![]);
+                components.len() - 1
+            });
+            components[i].push(x);
+        }
+        components
+    }
+}
+
+/// [`UnionFind`] over arbitrary labels, each label seen getting the next dense id.
+#[derive(Debug, Clone)]
+pub struct KeyedUnionFind<K> {
+    ids: HashMap<K, usize>,
+    keys: Vec<K>,
+    sets: UnionFind,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: vec![],
+            sets: UnionFind::new(0),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Id of `key`, added as a singleton if it is new.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&id) = self.ids.get(&key) {
+            return id;
+        }
+        let id = self.sets.push();
+        self.ids.insert(key.clone(), id);
+        self.keys.push(key);
+        id
+    }
+
+    pub fn id(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).copied()
+    }
+
+    pub fn key(&self, id: usize) -> &K {
+        &self.keys[id]
+    }
+
+    /// Merge the sets of `a` and `b`, inserting them if needed. False if they were already the
+    /// same.
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.sets.union(a, b)
+    }
+
+    /// Representative of the set of `key`.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let id = self.id(key)?;
+        let root = self.sets.find(id);
+        Some(&self.keys[root])
+    }
+
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.id(a), self.id(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => a == b,
+        }
+    }
+
+    /// Size of the set of `key`, zero if it was never inserted.
+    pub fn size_of(&mut self, key: &K) -> usize {
+        self.id(key).map_or(0, |id| self.sets.size_of(id))
+    }
+
+    pub fn sets(&self) -> usize {
+        self.sets.sets()
+    }
+
+    pub fn sizes(&self) -> Vec<usize> {
+        self.sets.sizes()
+    }
+
+    /// Members of every set, sets and members being in insertion order.
+    pub fn components(&mut self) -> Vec<Vec<&K>> {
+        self.sets
+            .components()
+            .into_iter()
+            .map(|ids| ids.into_iter().map(|id| &self.keys[id]).collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{KeyedUnionFind, UnionFind};
+
+    #[test]
+    fn test_union_find() {
+        let mut sets = UnionFind::new(6);
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 3));
+        assert!(sets.union(1, 3));
+        assert!(!sets.union(0, 2));
+        assert!(sets.connected(0, 3));
+        assert!(!sets.connected(0, 4));
+        assert_eq!(4, sets.size_of(2));
+        assert_eq!(3, sets.sets());
+        let mut sizes = sets.sizes();
+        sizes.sort_unstable();
+        assert_eq!(vec![1, 1, 4], sizes);
+        assert_eq!(vec![vec![0, 1, 2, 3], vec![4], vec![5]], sets.components());
+        assert_eq!(6, sets.push());
+        assert_eq!(4, sets.sets());
+    }
+
+    #[test]
+    fn test_keyed() {
+        let mut sets = KeyedUnionFind::new();
+        sets.union("jqt", "rhn");
+        sets.union("xhk", "hfx");
+        sets.union("rhn", "xhk");
+        sets.insert("cmg");
+        assert_eq!(5, sets.len());
+        assert_eq!(2, sets.sets());
+        assert!(sets.connected(&"jqt", &"hfx"));
+        assert!(!sets.connected(&"jqt", &"cmg"));
+        assert!(!sets.connected(&"jqt", &"nvd"));
+        assert_eq!(4, sets.size_of(&"hfx"));
+        assert_eq!(0, sets.size_of(&"nvd"));
+        assert_eq!(Some(2), sets.id(&"xhk"));
+        assert_eq!(&"cmg", sets.key(4));
+        assert_eq!(
+            vec![vec![&"jqt", &"rhn", &"xhk", &"hfx"], vec![&"cmg"]],
+            sets.components()
+        );
+    }
+}
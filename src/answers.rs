@@ -0,0 +1,109 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::Context;
+use aoc_lib::answer::Answer;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use toml::Value;
+
+pub fn answers_path(year: u16) -> String {
+    format!("aoc_{}/resources/answers.toml", year)
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_a: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_b: Option<Value>,
+}
+
+/// Known-good answers of a year, keyed by `day_NN` tables in the answers file.
+#[derive(Default, Debug)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+#[derive(Serialize, Deserialize, ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Answers {
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        if !Path::new(path).exists() {
+            return Ok(Self::default());
+        }
+        let content = fs::read_to_string(path).with_context(|| format!("cannot read {path}"))?;
+        let days = toml::from_str(&content).with_context(|| format!("cannot parse {path}"))?;
+        Ok(Self { days })
+    }
+
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        let content = toml::to_string(&self.days)?;
+        fs::write(path, content).with_context(|| format!("cannot write {path}"))
+    }
+
+    pub fn get(&self, day: u16, part: Part) -> Option<Answer> {
+        let answers = self.days.get(&day_key(day))?;
+        let value = match part {
+            Part::A => answers.part_a.as_ref(),
+            Part::B => answers.part_b.as_ref(),
+        }?;
+        match value {
+            Value::Integer(n) => usize::try_from(*n).ok().map(Answer::Number),
+            // numbers too large for a TOML integer are stored as strings
+            Value::String(s) => Some(match s.parse::<usize>() {
+                Ok(n) if i64::try_from(n).is_err() => Answer::Number(n),
+                _ => Answer::String(s.clone()),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Store `answer` for the given part, returns `false` if there is nothing to record.
+    pub fn set(&mut self, day: u16, part: Part, answer: &Answer) -> bool {
+        let value = match answer {
+            Answer::Number(n) => i64::try_from(*n)
+                .map(Value::Integer)
+                .unwrap_or_else(|_| Value::String(n.to_string())),
+            Answer::String(s) => Value::String(s.clone()),
+            Answer::Unimplemented => return false,
+        };
+        let answers = self.days.entry(day_key(day)).or_default();
+        match part {
+            Part::A => answers.part_a = Some(value),
+            Part::B => answers.part_b = Some(value),
+        }
+        true
+    }
+}
+
+fn day_key(day: u16) -> String {
+    format!("day_{:02}", day)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_lib::answer::Answer;
+
+    use super::{Answers, Part};
+
+    #[test]
+    fn test_large_numbers() {
+        let mut answers = Answers::default();
+        let large = Answer::Number(usize::MAX);
+        assert!(answers.set(1, Part::A, &large));
+        assert!(answers.set(1, Part::B, &Answer::Number(42)));
+        assert!(answers.set(2, Part::A, &Answer::String("123".to_string())));
+        assert!(!answers.set(2, Part::B, &Answer::Unimplemented));
+        assert_eq!(Some(large), answers.get(1, Part::A));
+        assert_eq!(Some(Answer::Number(42)), answers.get(1, Part::B));
+        assert_eq!(
+            Some(Answer::String("123".to_string())),
+            answers.get(2, Part::A)
+        );
+        assert_eq!(None, answers.get(2, Part::B));
+    }
+}
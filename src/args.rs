@@ -1,11 +1,101 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
+
+use crate::answers::Part;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
-    #[arg(short, long)]
-    pub day: u16,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Run both parts of a single day
+    Run {
+        #[arg(short, long)]
+        day: u16,
+
+        #[arg(short, long, default_value_t = 2024)]
+        year: u16,
+
+        /// Read the input from this file instead, `-` reading it from stdin
+        #[arg(short, long)]
+        input: Option<String>,
+
+        /// Download the input first if it is not cached yet
+        #[arg(long, conflicts_with = "input")]
+        auto_fetch: bool,
+    },
+    /// Run every registered day of one year, or of every year, and print a summary table
+    RunAll {
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Check answers against the recorded answers file of each year
+    Verify {
+        #[arg(short, long, requires = "year")]
+        day: Option<u16>,
+
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Record answers of parts that have no recorded answer yet
+        #[arg(long)]
+        record: bool,
+    },
+    /// Download the inputs of a single day, of one year or of every year, skipping cached ones
+    Fetch {
+        #[arg(short, long, requires = "year")]
+        day: Option<u16>,
+
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Submit the answer of a part, computing it when it is not given
+    Submit {
+        #[arg(short, long)]
+        day: u16,
+
+        #[arg(short, long, default_value_t = 2024)]
+        year: u16,
+
+        #[arg(short, long, value_enum)]
+        part: Part,
+
+        #[arg(short, long)]
+        answer: Option<String>,
+    },
+    /// Time each part over many runs and report min, median, mean and p95
+    Bench {
+        #[arg(short, long, requires = "year")]
+        day: Option<u16>,
+
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Number of timed runs of each part
+        #[arg(short = 'n', long, default_value_t = 10)]
+        iterations: usize,
+
+        /// Number of untimed runs of each part before measuring
+        #[arg(short, long, default_value_t = 3)]
+        warmup: usize,
+
+        /// Write the results as JSON to this file
+        #[arg(long)]
+        json: Option<String>,
+    },
+    /// Generate the module, test input and registration of a new day, and its year crate if needed
+    New {
+        #[arg(short, long)]
+        day: u16,
+
+        #[arg(short, long)]
+        year: u16,
 
-    #[arg(short, long, default_value_t = 2024)]
-    pub year: u16,
+        /// Title of the puzzle, as shown on the website
+        #[arg(short, long)]
+        title: String,
+    },
 }
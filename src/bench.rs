@@ -0,0 +1,181 @@
+use std::{fs, time::Duration};
+
+use anyhow::Context;
+use aoc_lib::{error::AocError, registry::Entry};
+use serde::Serialize;
+
+use crate::{
+    inputs::Inputs,
+    runner::{self, Failure},
+    table,
+};
+
+/// Timing statistics of one part, in nanoseconds so that runs are easy to diff.
+#[derive(Serialize, Debug)]
+pub struct Stats {
+    pub min: u128,
+    pub median: u128,
+    pub mean: u128,
+    pub p95: u128,
+}
+
+#[derive(Serialize, Debug)]
+pub struct Measure {
+    pub year: u16,
+    pub day: u16,
+    pub step: String,
+    pub iterations: usize,
+    pub stats: Stats,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let n = samples.len();
+        let total = samples.iter().sum::<Duration>();
+        // nearest-rank percentile
+        let p95 = (n * 95).div_ceil(100).max(1) - 1;
+        Self {
+            min: samples[0].as_nanos(),
+            median: samples[n / 2].as_nanos(),
+            mean: total.as_nanos() / n as u128,
+            p95: samples[p95].as_nanos(),
+        }
+    }
+}
+
+/// Run the parse step and each part of `entries` `warmup` times untimed, then `iterations` timed
+/// times, the parts reusing a single parsed value. Days without input and steps that panic are
+/// reported and skipped.
+pub fn bench<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    inputs: &Inputs,
+    warmup: usize,
+    iterations: usize,
+) -> Vec<Measure> {
+    let iterations = iterations.max(1);
+    let mut measures = vec![];
+    runner::silence_panics(|| {
+        for entry in entries {
+            let input = match inputs.read(entry.year, entry.day) {
+                Ok(input) => input,
+                Err(error) => {
+                    println!(
+                        "{} day {:02}: missing input: {error:#}",
+                        entry.year, entry.day
+                    );
+                    continue;
+                }
+            };
+            let mut measure = |step: &str, samples: Result<Vec<Duration>, Failure>| match samples {
+                Ok(samples) => measures.push(Measure {
+                    year: entry.year,
+                    day: entry.day,
+                    step: step.to_owned(),
+                    iterations,
+                    stats: Stats::from_samples(samples),
+                }),
+                Err(failure) => println!("{} day {:02} {step}: {failure}", entry.year, entry.day),
+            };
+
+            measure(
+                "parse",
+                sample(warmup, iterations, || entry.solution.parse_dyn(&input)),
+            );
+            let (Ok(parsed), _) = runner::timed(|| entry.solution.parse_dyn(&input)) else {
+                continue;
+            };
+            measure(
+                "part A",
+                sample(warmup, iterations, || entry.solution.part_a_dyn(&*parsed)),
+            );
+            measure(
+                "part B",
+                sample(warmup, iterations, || entry.solution.part_b_dyn(&*parsed)),
+            );
+        }
+    });
+    measures
+}
+
+fn sample<R>(
+    warmup: usize,
+    iterations: usize,
+    f: impl Fn() -> Result<R, AocError>,
+) -> Result<Vec<Duration>, Failure> {
+    let mut samples = Vec::with_capacity(iterations);
+    for i in 0..warmup + iterations {
+        let (result, elapsed) = runner::timed(&f);
+        result?;
+        if i >= warmup {
+            samples.push(elapsed);
+        }
+    }
+    Ok(samples)
+}
+
+pub fn print_table(measures: &[Measure]) {
+    let rows = measures
+        .iter()
+        .map(|m| {
+            [
+                m.year.to_string(),
+                m.day.to_string(),
+                m.step.clone(),
+                m.iterations.to_string(),
+                format_nanos(m.stats.min),
+                format_nanos(m.stats.median),
+                format_nanos(m.stats.mean),
+                format_nanos(m.stats.p95),
+            ]
+        })
+        .collect::<Vec<_>>();
+    table::print(
+        [
+            "year", "day", "step", "runs", "min", "median", "mean", "p95",
+        ],
+        &rows,
+    );
+}
+
+pub fn write_json(measures: &[Measure], path: &str) -> anyhow::Result<()> {
+    let content = serde_json::to_string_pretty(measures)?;
+    fs::write(path, content).with_context(|| format!("cannot write {path}"))
+}
+
+fn format_nanos(nanos: u128) -> String {
+    format!("{:.2?}", Duration::from_nanos(nanos as u64))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn stats(nanos: impl DoubleEndedIterator<Item = u64>) -> Stats {
+        // given in decreasing order to check that the samples get sorted
+        Stats::from_samples(nanos.rev().map(Duration::from_nanos).collect())
+    }
+
+    #[test]
+    fn test_stats() {
+        let single = stats(7..=7);
+        assert_eq!(
+            (7, 7, 7, 7),
+            (single.min, single.median, single.mean, single.p95)
+        );
+        // the median of an even number of samples is the upper middle one
+        let even = stats([10, 20, 30, 100].into_iter());
+        assert_eq!(
+            (10, 30, 40, 100),
+            (even.min, even.median, even.mean, even.p95)
+        );
+        // with 20 samples, 19 of them are at or below the 95th percentile
+        let twenty = stats(1..=20);
+        assert_eq!(
+            (1, 11, 10, 19),
+            (twenty.min, twenty.median, twenty.mean, twenty.p95)
+        );
+    }
+}
@@ -0,0 +1,40 @@
+use std::{env, fs, path::PathBuf};
+
+use anyhow::Context;
+use serde::Deserialize;
+
+/// Optional personal settings, read from `aoc.toml` in the working directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Deserialize, Default, Debug)]
+pub struct Config {
+    /// Directory holding the puzzle inputs as `{year}/day_{day}.txt`.
+    pub inputs_root: Option<PathBuf>,
+    /// Value of the `session` cookie of the puzzle website, used to download inputs.
+    pub session: Option<String>,
+    /// Base URL of the puzzle website, e.g. a local stub server.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> anyhow::Result<Self> {
+        let Ok(content) = fs::read_to_string(CONFIG_FILE) else {
+            return Ok(Self::default());
+        };
+        toml::from_str(&content).with_context(|| format!("cannot parse {CONFIG_FILE}"))
+    }
+
+    /// The session token, the `AOC_SESSION` environment variable taking precedence.
+    pub fn session(&self) -> Option<String> {
+        env::var("AOC_SESSION")
+            .ok()
+            .or_else(|| self.session.clone())
+    }
+
+    /// The base URL, the `AOC_BASE_URL` environment variable taking precedence.
+    pub fn base_url(&self) -> Option<String> {
+        env::var("AOC_BASE_URL")
+            .ok()
+            .or_else(|| self.base_url.clone())
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    fs,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use aoc_lib::submit::Verdict;
+use serde::{Deserialize, Serialize};
+
+use crate::answers::Part;
+
+/// Local record of submitted answers, read from the working directory.
+pub const HISTORY_FILE: &str = "submissions.json";
+
+// the website asks to wait at least a minute after a wrong answer
+const WRONG_ANSWER_WAIT: u64 = 60;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u16,
+    pub part: Part,
+    pub answer: String,
+    pub outcome: Outcome,
+    pub timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct History {
+    /// Unix time before which the website refuses any submission.
+    #[serde(default)]
+    pub wait_until: u64,
+    #[serde(default)]
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: &str) -> anyhow::Result<Self> {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Ok(Self::default());
+        };
+        serde_json::from_str(&content).with_context(|| format!("cannot parse {path}"))
+    }
+
+    pub fn save(&self, path: &str) -> anyhow::Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(path, content).with_context(|| format!("cannot write {path}"))
+    }
+
+    fn attempts(&self, year: u16, day: u16, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    /// Explain why `answer` must not be submitted, if the history already tells how it would be judged.
+    pub fn check(&self, year: u16, day: u16, part: Part, answer: &str, now: u64) -> Option<String> {
+        if now < self.wait_until {
+            return Some(format!(
+                "submitted too recently, wait {}s",
+                self.wait_until - now
+            ));
+        }
+        let value = answer.parse::<i128>().ok();
+        for attempt in self.attempts(year, day, part) {
+            if attempt.outcome == Outcome::Correct {
+                return Some(format!("already solved with {}", attempt.answer));
+            }
+            if attempt.answer == answer {
+                return Some(format!("{answer} was already rejected"));
+            }
+            let (Some(value), Ok(bound)) = (value, attempt.answer.parse::<i128>()) else {
+                continue;
+            };
+            match attempt.outcome {
+                Outcome::TooHigh if value >= bound => {
+                    return Some(format!("{answer} is above {bound}, known to be too high"));
+                }
+                Outcome::TooLow if value <= bound => {
+                    return Some(format!("{answer} is below {bound}, known to be too low"));
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Record the verdict of a submission, verdicts that do not judge the answer only update the
+    /// waiting time.
+    pub fn record(
+        &mut self,
+        year: u16,
+        day: u16,
+        part: Part,
+        answer: &str,
+        verdict: &Verdict,
+        now: u64,
+    ) {
+        let outcome = match verdict {
+            Verdict::Correct => Outcome::Correct,
+            Verdict::TooHigh => Outcome::TooHigh,
+            Verdict::TooLow => Outcome::TooLow,
+            Verdict::Incorrect => Outcome::Incorrect,
+            Verdict::Wait(seconds) => {
+                self.wait_until = now + seconds;
+                return;
+            }
+            Verdict::WrongLevel | Verdict::Unknown(_) => return,
+        };
+        if outcome != Outcome::Correct {
+            self.wait_until = now + WRONG_ANSWER_WAIT;
+        }
+        self.attempts.push(Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_owned(),
+            outcome,
+            timestamp: now,
+        });
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc_lib::submit::Verdict;
+
+    use super::{History, WRONG_ANSWER_WAIT};
+    use crate::answers::Part;
+
+    #[test]
+    fn test_bounds() {
+        let mut history = History::default();
+        history.record(2024, 1, Part::A, "100", &Verdict::TooHigh, 0);
+        history.record(2024, 1, Part::A, "50", &Verdict::TooLow, 0);
+        history.record(2024, 1, Part::A, "70", &Verdict::Incorrect, 0);
+        let check = |answer| history.check(2024, 1, Part::A, answer, WRONG_ANSWER_WAIT);
+        assert_eq!(Some("100 was already rejected".to_owned()), check("100"));
+        assert_eq!(Some("70 was already rejected".to_owned()), check("70"));
+        // equal to a bound but written differently
+        assert_eq!(
+            Some("0100 is above 100, known to be too high".to_owned()),
+            check("0100")
+        );
+        assert_eq!(
+            Some("050 is below 50, known to be too low".to_owned()),
+            check("050")
+        );
+        assert!(check("101").is_some());
+        assert!(check("49").is_some());
+        assert_eq!(None, check("99"));
+        assert_eq!(None, check("51"));
+        assert_eq!(None, check("abc"));
+        // other parts and days are not affected
+        assert_eq!(
+            None,
+            history.check(2024, 1, Part::B, "100", WRONG_ANSWER_WAIT)
+        );
+        assert_eq!(
+            None,
+            history.check(2024, 2, Part::A, "100", WRONG_ANSWER_WAIT)
+        );
+    }
+
+    #[test]
+    fn test_solved() {
+        let mut history = History::default();
+        history.record(2024, 1, Part::A, "42", &Verdict::Correct, 0);
+        assert_eq!(0, history.wait_until);
+        assert_eq!(
+            Some("already solved with 42".to_owned()),
+            history.check(2024, 1, Part::A, "42", 0)
+        );
+        assert_eq!(
+            Some("already solved with 42".to_owned()),
+            history.check(2024, 1, Part::A, "43", 0)
+        );
+    }
+
+    #[test]
+    fn test_wait() {
+        let mut history = History::default();
+        history.record(2024, 1, Part::A, "1", &Verdict::Incorrect, 100);
+        assert_eq!(100 + WRONG_ANSWER_WAIT, history.wait_until);
+        assert_eq!(
+            Some("submitted too recently, wait 1s".to_owned()),
+            history.check(2024, 1, Part::B, "2", 159)
+        );
+        assert_eq!(None, history.check(2024, 1, Part::B, "2", 160));
+        history.record(2024, 1, Part::B, "2", &Verdict::Wait(30), 200);
+        assert_eq!(230, history.wait_until);
+        assert!(history.check(2024, 1, Part::B, "2", 229).is_some());
+        assert_eq!(None, history.check(2024, 1, Part::B, "2", 230));
+        // a verdict that does not judge the answer is not recorded
+        history.record(2024, 1, Part::B, "2", &Verdict::WrongLevel, 300);
+        assert_eq!(1, history.attempts.len());
+    }
+}
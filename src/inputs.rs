@@ -0,0 +1,44 @@
+use std::{env, path::PathBuf};
+
+use anyhow::Context;
+use aoc_lib::input;
+
+use crate::config::Config;
+
+/// Environment variable overriding the `inputs_root` of the config file.
+pub const INPUTS_ROOT_VAR: &str = "AOC_INPUTS";
+
+/// Location of the puzzle inputs, which default to `aoc_{year}/resources/real/` inside the
+/// repository, or to `{root}/{year}/` when an inputs root is configured.
+pub struct Inputs {
+    root: Option<PathBuf>,
+}
+
+impl Inputs {
+    pub fn new(config: &Config) -> Self {
+        let root = env::var_os(INPUTS_ROOT_VAR)
+            .map(PathBuf::from)
+            .or_else(|| config.inputs_root.clone());
+        Self { root }
+    }
+
+    pub fn path(&self, year: u16, day: u16) -> PathBuf {
+        let file = format!("day_{:02}.txt", day);
+        match &self.root {
+            Some(root) => root.join(year.to_string()).join(file),
+            None => PathBuf::from(format!("aoc_{year}/resources/real")).join(file),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u16) -> anyhow::Result<Vec<String>> {
+        read(&self.path(year, day).to_string_lossy())
+    }
+}
+
+/// Read an input from `path`, or from the standard input when `path` is `-`.
+pub fn read(path: &str) -> anyhow::Result<Vec<String>> {
+    if path == "-" {
+        return input::read_stdin().context("cannot read input from stdin");
+    }
+    input::read_file(path).with_context(|| format!("cannot read input file `{path}`"))
+}
@@ -1,28 +1,178 @@
+mod answers;
 mod args;
+mod bench;
+mod config;
+mod history;
+mod inputs;
+mod runner;
+mod scaffold;
+mod submit;
+mod table;
+mod verify;
 
-use aoc_lib::{input, solution::Solution};
-use args::Args;
+use answers::Part;
+use anyhow::Context;
+use aoc_lib::{
+    answer::Answer,
+    fetch::{Fetched, Fetcher, UreqBackend},
+    registry::{Entry, Registry, RegistryError},
+    submit::Submitter,
+};
+use args::{Args, Command};
 use clap::Parser;
+use config::Config;
+use inputs::Inputs;
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
-    let day = args.day;
-    let year = args.year;
-    let solutions = get_year(year);
-    let solution = solutions[day as usize - 1];
-    let input_path = format!("aoc_{}/resources/real/day_{:02}.txt", year, day);
-    let input = input::read_file(&input_path)?;
-    println!("Running Solutions for day {day}");
-    println!("Answer for part A : {}", solution.part_a(&input));
-    println!("Answer for part B : {}", solution.part_b(&input));
+    let registry = registry();
+    let config = Config::load()?;
+    let inputs = Inputs::new(&config);
+    match args.command {
+        Command::Run {
+            day,
+            year,
+            input,
+            auto_fetch,
+        } => {
+            let entry = registry.get(year, day)?;
+            let path = inputs.path(year, day);
+            if auto_fetch && !path.exists() {
+                fetcher(&config)?.fetch(year, day, &path)?;
+            }
+            let input = match input {
+                Some(path) => inputs::read(&path)?,
+                None => inputs.read(year, day)?,
+            };
+            println!("Running Solutions for day {day}: {}", entry.title);
+            let located = |error| anyhow::anyhow!("day {day}, {error}");
+            let parsed = entry.solution.parse_dyn(&input).map_err(located)?;
+            let answer_a = entry.solution.part_a_dyn(&*parsed).map_err(located)?;
+            println!("Answer for part A : {answer_a}");
+            let answer_b = entry.solution.part_b_dyn(&*parsed).map_err(located)?;
+            println!("Answer for part B : {answer_b}");
+        }
+        Command::RunAll { year } => {
+            let entries = select(&registry, year, None)?;
+            runner::print_table(&runner::run_all(entries.into_iter(), &inputs));
+        }
+        Command::Verify { day, year, record } => {
+            let entries = select(&registry, year, day)?;
+            verify::verify(entries.into_iter(), &inputs, record)?;
+        }
+        Command::Fetch { day, year } => {
+            // the session token is only needed once an input has to be downloaded
+            let mut client = None;
+            for entry in select(&registry, year, day)? {
+                let path = inputs.path(entry.year, entry.day);
+                let fetched = if path.exists() {
+                    Fetched::Cached
+                } else {
+                    let fetcher = match &mut client {
+                        Some(fetcher) => fetcher,
+                        None => client.insert(fetcher(&config)?),
+                    };
+                    fetcher.fetch(entry.year, entry.day, &path)?
+                };
+                let status = match fetched {
+                    Fetched::Cached => "cached at",
+                    Fetched::Downloaded => "downloaded to",
+                };
+                println!(
+                    "{} day {:02}: {status} {}",
+                    entry.year,
+                    entry.day,
+                    path.display()
+                );
+            }
+        }
+        Command::Submit {
+            day,
+            year,
+            part,
+            answer,
+        } => {
+            let entry = registry.get(year, day)?;
+            let answer = match answer {
+                Some(answer) => answer,
+                None => {
+                    let located = |error| anyhow::anyhow!("day {day}, {error}");
+                    let input = inputs.read(year, day)?;
+                    let parsed = entry.solution.parse_dyn(&input).map_err(located)?;
+                    let answer = match part {
+                        Part::A => entry.solution.part_a_dyn(&*parsed),
+                        Part::B => entry.solution.part_b_dyn(&*parsed),
+                    }
+                    .map_err(located)?;
+                    if answer == Answer::Unimplemented {
+                        anyhow::bail!("part {part:?} of {year} day {day:02} is not implemented");
+                    }
+                    answer.to_string()
+                }
+            };
+            submit::submit(&submitter(&config)?, year, day, part, &answer)?;
+        }
+        Command::Bench {
+            day,
+            year,
+            iterations,
+            warmup,
+            json,
+        } => {
+            let entries = select(&registry, year, day)?;
+            let measures = bench::bench(entries.into_iter(), &inputs, warmup, iterations);
+            bench::print_table(&measures);
+            if let Some(path) = json {
+                bench::write_json(&measures, &path)?;
+            }
+        }
+        Command::New { day, year, title } => {
+            for path in scaffold::new_day(year, day, &title)? {
+                println!("updated {path}");
+            }
+        }
+    }
     Ok(())
 }
 
-fn get_year(year: u16) -> &'static [&'static dyn Solution] {
-    match year {
-        2023 => aoc_2023::ALL,
-        2024 => aoc_2024::ALL,
-        2025 => aoc_2025::ALL,
-        _ => &[],
+fn session(config: &Config) -> anyhow::Result<String> {
+    config
+        .session()
+        .context("a session token is needed, set AOC_SESSION or `session` in aoc.toml")
+}
+
+fn fetcher(config: &Config) -> anyhow::Result<Fetcher<UreqBackend>> {
+    let fetcher = Fetcher::new(UreqBackend::default(), session(config)?);
+    Ok(match config.base_url() {
+        Some(base_url) => fetcher.with_base_url(base_url),
+        None => fetcher,
+    })
+}
+
+fn submitter(config: &Config) -> anyhow::Result<Submitter<UreqBackend>> {
+    let submitter = Submitter::new(UreqBackend::default(), session(config)?);
+    Ok(match config.base_url() {
+        Some(base_url) => submitter.with_base_url(base_url),
+        None => submitter,
+    })
+}
+
+fn registry() -> Registry {
+    Registry::new()
+        .with(aoc_2023::SOLUTIONS)
+        .with(aoc_2024::SOLUTIONS)
+        .with(aoc_2025::SOLUTIONS)
+}
+
+/// Entries of a single day, a whole year or every year, depending on what is given.
+fn select(
+    registry: &Registry,
+    year: Option<u16>,
+    day: Option<u16>,
+) -> Result<Vec<&Entry>, RegistryError> {
+    match (year, day) {
+        (Some(year), Some(day)) => Ok(vec![registry.get(year, day)?]),
+        (Some(year), None) => Ok(registry.year(year)?.collect()),
+        _ => Ok(registry.iter().collect()),
     }
 }
@@ -0,0 +1,195 @@
+use std::{
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+use aoc_lib::{answer::Answer, error::AocError, registry::Entry};
+
+use crate::{inputs::Inputs, table};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    Ok,
+    Unimplemented,
+    /// The input file tried could not be read.
+    MissingInput(PathBuf),
+    Failed,
+    Panicked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Unimplemented => write!(f, "unimplemented"),
+            Status::MissingInput(path) => write!(f, "missing input `{}`", path.display()),
+            Status::Failed => write!(f, "error"),
+            Status::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Failure {
+    Error(AocError),
+    Panicked(String),
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Error(error) => write!(f, "{error}"),
+            Failure::Panicked(message) => write!(f, "panicked ({message})"),
+        }
+    }
+}
+
+pub struct Part {
+    pub answer: Result<Answer, Failure>,
+    pub elapsed: Duration,
+}
+
+pub struct Report {
+    pub year: u16,
+    pub day: u16,
+    /// Path of the input, the parts being `None` when it could not be read.
+    pub input: PathBuf,
+    pub parse: Option<Duration>,
+    pub part_a: Option<Part>,
+    pub part_b: Option<Part>,
+}
+
+impl Report {
+    pub fn status(&self) -> Status {
+        let (Some(a), Some(b)) = (&self.part_a, &self.part_b) else {
+            return Status::MissingInput(self.input.clone());
+        };
+        let failures = [&a.answer, &b.answer].map(|answer| answer.as_ref().err());
+        if failures
+            .iter()
+            .any(|f| matches!(f, Some(Failure::Panicked(_))))
+        {
+            Status::Panicked
+        } else if failures.iter().any(Option::is_some) {
+            Status::Failed
+        } else if [a, b]
+            .iter()
+            .any(|p| matches!(p.answer, Ok(Answer::Unimplemented)))
+        {
+            Status::Unimplemented
+        } else {
+            Status::Ok
+        }
+    }
+}
+
+/// Run `f`, catching any panic so that a sweep over many days keeps going.
+pub fn timed<R>(f: impl FnOnce() -> Result<R, AocError>) -> (Result<R, Failure>, Duration) {
+    let start = Instant::now();
+    let result = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(payload) => Err(Failure::Panicked(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_owned()),
+        )),
+    };
+    (result, start.elapsed())
+}
+
+pub fn run_part(part: impl FnOnce() -> Result<Answer, AocError>) -> Part {
+    let (answer, elapsed) = timed(part);
+    Part { answer, elapsed }
+}
+
+pub fn run_entry(entry: &Entry, inputs: &Inputs) -> Report {
+    let mut report = Report {
+        year: entry.year,
+        day: entry.day,
+        input: inputs.path(entry.year, entry.day),
+        parse: None,
+        part_a: None,
+        part_b: None,
+    };
+    let Ok(input) = inputs.read(entry.year, entry.day) else {
+        return report;
+    };
+    let (parsed, elapsed) = timed(|| entry.solution.parse_dyn(&input));
+    report.parse = Some(elapsed);
+    match parsed {
+        Ok(parsed) => {
+            report.part_a = Some(run_part(|| entry.solution.part_a_dyn(&*parsed)));
+            report.part_b = Some(run_part(|| entry.solution.part_b_dyn(&*parsed)));
+        }
+        Err(failure) => {
+            let failed = || Part {
+                answer: Err(failure.clone()),
+                elapsed: Duration::ZERO,
+            };
+            report.part_a = Some(failed());
+            report.part_b = Some(failed());
+        }
+    }
+    report
+}
+
+pub fn run_all<'a>(entries: impl Iterator<Item = &'a Entry>, inputs: &Inputs) -> Vec<Report> {
+    silence_panics(|| entries.map(|e| run_entry(e, inputs)).collect())
+}
+
+/// Run `f` with panic messages silenced, the caught panics being reported by the caller instead.
+pub fn silence_panics<R>(f: impl FnOnce() -> R) -> R {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+pub fn print_table(reports: &[Report]) {
+    let rows = reports
+        .iter()
+        .map(|r| {
+            let (answer_a, time_a) = format_part(&r.part_a);
+            let (answer_b, time_b) = format_part(&r.part_b);
+            [
+                r.year.to_string(),
+                r.day.to_string(),
+                r.parse.map_or("-".to_owned(), |p| format!("{p:.2?}")),
+                answer_a,
+                time_a,
+                answer_b,
+                time_b,
+                r.status().to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+    table::print(
+        [
+            "year", "day", "parse", "part A", "time A", "part B", "time B", "status",
+        ],
+        &rows,
+    );
+}
+
+fn format_part(part: &Option<Part>) -> (String, String) {
+    match part {
+        None => ("-".to_owned(), "-".to_owned()),
+        Some(part) => {
+            let answer = match &part.answer {
+                Ok(Answer::Unimplemented) => "-".to_owned(),
+                Ok(answer) => answer.to_string(),
+                Err(Failure::Error(error)) => {
+                    let message = error.to_string();
+                    format!("error: {}", message.lines().next().unwrap_or_default())
+                }
+                Err(Failure::Panicked(_)) => "panic".to_owned(),
+            };
+            (answer, format!("{:.2?}", part.elapsed))
+        }
+    }
+}
@@ -0,0 +1,512 @@
+use std::{
+    fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use anyhow::Context;
+
+/// Generate the module, test resource and registration of a new day, creating the year crate
+/// first if it does not exist. Returns the paths that were created or modified.
+pub fn new_day(year: u16, day: u16, title: &str) -> anyhow::Result<Vec<String>> {
+    if !(1..=25).contains(&day) {
+        anyhow::bail!("day {day} is not between 1 and 25");
+    }
+    let mut touched = vec![];
+    if !Path::new(&format!("aoc_{year}")).exists() {
+        touched.extend(new_year(year)?);
+    }
+
+    let module = format!("aoc_{year}/src/day{day:02}.rs");
+    if Path::new(&module).exists() {
+        anyhow::bail!("{module} already exists");
+    }
+    let edition = edition(&format!("aoc_{year}"))?;
+    write(&module, &rustfmt(&day_template(day), &edition)?)?;
+    touched.push(module);
+
+    let resource = format!("aoc_{year}/resources/test/day_{day:02}_test.txt");
+    if !Path::new(&resource).exists() {
+        write(&resource, "")?;
+        touched.push(resource);
+    }
+
+    let lib = format!("aoc_{year}/src/lib.rs");
+    let content = read(&lib)?;
+    let content = insert_sorted(
+        &content,
+        "pub mod day",
+        &format!("pub mod day{day:02};"),
+        Some("pub const YEAR"),
+    )?;
+    let content = insert_entry(&content, day, title)?;
+    write(&lib, &rustfmt(&content, &edition)?)?;
+    touched.push(lib);
+
+    let readme = "README.md";
+    let content = read(readme)?;
+    let section = format!("### {year}\n");
+    if let Some(start) = content.find(&section) {
+        let rest = &content[start + section.len()..];
+        let end = ["\n## ", "\n### "]
+            .iter()
+            .filter_map(|heading| rest.find(heading))
+            .min()
+            .map_or(content.len(), |i| start + section.len() + i);
+        let line = format!("- [Day {day:02}: {title}](aoc_{year}/src/day{day:02}.rs)");
+        let updated = insert_sorted(&content[start..end], "- [Day ", &line, None)?;
+        write(
+            readme,
+            &format!("{}{}{}", &content[..start], updated, &content[end..]),
+        )?;
+        touched.push(readme.to_owned());
+    }
+    Ok(touched)
+}
+
+/// Generate the `aoc_{year}` crate and wire it into the workspace, the binary and the CI.
+fn new_year(year: u16) -> anyhow::Result<Vec<String>> {
+    let krate = format!("aoc_{year}");
+    let mut touched = vec![];
+
+    let manifest = format!("{krate}/Cargo.toml");
+    write(&manifest, &manifest_template(year))?;
+    touched.push(manifest);
+
+    let lib = format!("{krate}/src/lib.rs");
+    write(&lib, &lib_template(year))?;
+    touched.push(lib);
+
+    let root = "Cargo.toml";
+    let content = read(root)?;
+    let members = content
+        .lines()
+        .find(|l| l.starts_with("members = ["))
+        .context("cannot find the workspace members")?;
+    let updated = members.replace("\"]", &format!("\", \"{krate}\"]"));
+    let content = content.replacen(members, &updated, 1);
+    let content = insert_after_last(
+        &content,
+        "aoc_20",
+        &format!("{krate} = {{ path = \"{krate}\" }}"),
+    )?;
+    write(root, &content)?;
+    touched.push(root.to_owned());
+
+    let main = "src/main.rs";
+    let content = read(main)?;
+    let content = insert_after_last(
+        &content,
+        "        .with(aoc_20",
+        &format!("        .with({krate}::SOLUTIONS)"),
+    )?;
+    write(main, &content)?;
+    touched.push(main.to_owned());
+
+    let workflow = format!(".github/workflows/{krate}.yaml");
+    write(&workflow, &workflow_template(year))?;
+    touched.push(workflow);
+
+    let readme = "README.md";
+    let content = read(readme)?;
+    let start = content
+        .find("## Thanks")
+        .context("cannot find where to add the year to the README")?;
+    let badge = format!(
+        "[![{krate}](https://github.com/dirdr/advent_of_code/actions/workflows/{krate}.yaml/badge.svg)]\
+         (https://github.com/dirdr/advent_of_code/actions/workflows/{krate}.yaml)"
+    );
+    let section = format!("### {year}\n\n{badge}\n\n");
+    write(
+        readme,
+        &format!("{}{section}{}", &content[..start], &content[start..]),
+    )?;
+    touched.push(readme.to_owned());
+
+    Ok(touched)
+}
+
+/// Insert `line` among the lines starting with `prefix`, keeping them sorted. When there are
+/// none yet, the line is inserted as its own paragraph before the line starting with `before`, or
+/// at the end.
+fn insert_sorted(
+    content: &str,
+    prefix: &str,
+    line: &str,
+    before: Option<&str>,
+) -> anyhow::Result<String> {
+    let mut lines = content.lines().map(str::to_owned).collect::<Vec<_>>();
+    let matching = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with(prefix))
+        .map(|(i, l)| (i, l.clone()))
+        .collect::<Vec<_>>();
+    if matching.iter().any(|(_, l)| l == line) {
+        anyhow::bail!("`{line}` is already there");
+    }
+    if let Some((last, _)) = matching.last() {
+        let index = matching
+            .iter()
+            .find(|(_, l)| l.as_str() > line)
+            .map_or(last + 1, |(i, _)| *i);
+        lines.insert(index, line.to_owned());
+    } else if let Some(index) = before.and_then(|b| lines.iter().position(|l| l.starts_with(b))) {
+        lines.insert(index, String::new());
+        lines.insert(index, line.to_owned());
+    } else {
+        if lines.last().is_some_and(|l| !l.is_empty()) {
+            lines.push(String::new());
+        }
+        lines.push(line.to_owned());
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn insert_after_last(content: &str, prefix: &str, line: &str) -> anyhow::Result<String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let last = lines
+        .iter()
+        .rposition(|l| l.starts_with(prefix))
+        .with_context(|| format!("cannot find a line starting with `{prefix}`"))?;
+    lines.insert(last + 1, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+enum Item {
+    Entry(u16, String),
+    // a whole line comment, kept as is, with the day it comments out if any
+    Comment(Option<u16>, String),
+}
+
+/// Register the day in the `SOLUTIONS` slice, ordered by day. Entries are written one per
+/// line, whatever their formatting was, and left for rustfmt to lay out. Line comments are kept,
+/// a commented out entry keeping its place among the days.
+fn insert_entry(content: &str, day: u16, title: &str) -> anyhow::Result<String> {
+    let head = "pub const SOLUTIONS: &[Entry] = &[";
+    let start = content
+        .find(head)
+        .context("cannot find the SOLUTIONS slice")?
+        + head.len();
+    let body = &content[start..];
+    let mut items = vec![];
+    let mut current = String::new();
+    let (mut depth, mut in_string, mut escaped) = (0, false, false);
+    let mut end = None;
+    let mut chars = body.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if in_string {
+            current.push(c);
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '/' if chars.peek().is_some_and(|&(_, next)| next == '/') => {
+                let comment = body[i..].lines().next().unwrap_or_default();
+                let before = body[..i].rsplit('\n').next().unwrap_or_default();
+                if !before.trim().is_empty() || !current.trim().is_empty() {
+                    anyhow::bail!("cannot edit the SOLUTIONS slice around `{comment}`");
+                }
+                let commented = comment.trim_start_matches('/').trim();
+                let day = entry_args(commented.trim_end_matches(','))
+                    .ok()
+                    .map(|(d, _)| d);
+                items.push(Item::Comment(day, comment.trim_end().to_owned()));
+                while chars.next_if(|&(_, c)| c != '\n').is_some() {}
+            }
+            ',' | ']' if depth == 0 => {
+                if !current.trim().is_empty() {
+                    let (day, args) = entry_args(&current)?;
+                    items.push(Item::Entry(day, args));
+                }
+                current.clear();
+                if c == ']' {
+                    end = Some(start + i);
+                    break;
+                }
+            }
+            _ => {
+                match c {
+                    '"' => in_string = true,
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                current.push(c);
+            }
+        }
+    }
+    let end = end.context("cannot find the end of the SOLUTIONS slice")?;
+
+    let day_of = |item: &Item| match item {
+        Item::Entry(d, _) => Some(*d),
+        Item::Comment(d, _) => *d,
+    };
+    if items.iter().any(|item| day_of(item) == Some(day)) {
+        anyhow::bail!("day {day} is already registered");
+    }
+    let index = items
+        .iter()
+        .position(|item| day_of(item).is_some_and(|d| d > day))
+        .unwrap_or(items.len());
+    let args = format!("YEAR, {day}, {title:?}, &day{day:02}::Day{day}");
+    items.insert(index, Item::Entry(day, args));
+    let items = items
+        .iter()
+        .map(|item| match item {
+            Item::Entry(_, args) => format!("    Entry::new({args}),\n"),
+            Item::Comment(_, comment) => format!("    {comment}\n"),
+        })
+        .collect::<String>();
+    Ok(format!("{}\n{items}{}", &content[..start], &content[end..]))
+}
+
+// day and arguments of `Entry::new(..)`, its whitespace collapsed
+fn entry_args(entry: &str) -> anyhow::Result<(u16, String)> {
+    let entry = entry.split_whitespace().collect::<Vec<_>>().join(" ");
+    let args = entry
+        .strip_prefix("Entry::new(")
+        .and_then(|args| args.strip_suffix(')'))
+        .with_context(|| format!("expected `Entry::new(..)`, found `{entry}`"))?
+        .trim_start()
+        .trim_end_matches([',', ' ']);
+    let day = args
+        .split(", ")
+        .nth(1)
+        .and_then(|d| d.parse::<u16>().ok())
+        .with_context(|| format!("cannot read the day of `{entry}`"))?;
+    Ok((day, args.to_owned()))
+}
+
+/// Format `code` with rustfmt, given on the standard input so that the modules it declares are
+/// left untouched.
+fn rustfmt(code: &str, edition: &str) -> anyhow::Result<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", edition])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .context("cannot run rustfmt")?;
+    child
+        .stdin
+        .take()
+        .context("cannot write to rustfmt")?
+        .write_all(code.as_bytes())?;
+    let output = child.wait_with_output()?;
+    anyhow::ensure!(
+        output.status.success(),
+        "rustfmt failed on the generated code"
+    );
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+// edition of the crate, read from its manifest
+fn edition(krate: &str) -> anyhow::Result<String> {
+    let manifest = read(&format!("{krate}/Cargo.toml"))?;
+    Ok(manifest
+        .lines()
+        .find_map(|l| l.strip_prefix("edition = "))
+        .map_or("2021", |e| e.trim_matches('"'))
+        .to_owned())
+}
+
+fn read(path: &str) -> anyhow::Result<String> {
+    fs::read_to_string(path).with_context(|| format!("cannot read {path}"))
+}
+
+fn write(path: &str, content: &str) -> anyhow::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content).with_context(|| format!("cannot write {path}"))
+}
+
+fn day_template(day: u16) -> String {
+    format!(
+        r#"use aoc_lib::{{answer::Answer, solution::Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    fn part_a(&self, _input: &[String]) -> Answer {{
+        Answer::Unimplemented
+    }}
+
+    fn part_b(&self, _input: &[String]) -> Answer {{
+        Answer::Unimplemented
+    }}
+}}
+
+#[cfg(test)]
+mod test {{
+    use aoc_lib::{{answer::Answer, input, solution::Solution}};
+
+    use super::Day{day};
+
+    #[test]
+    fn test_a() {{
+        let input =
+            input::read_file(&format!("{{}}day_{day:02}_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
+        let answer = Day{day}.part_a(&input);
+        assert_eq!(Answer::Unimplemented, answer);
+    }}
+
+    #[test]
+    fn test_b() {{
+        let input =
+            input::read_file(&format!("{{}}day_{day:02}_test.txt", crate::FILES_PREFIX_TEST)).unwrap();
+        let answer = Day{day}.part_b(&input);
+        assert_eq!(Answer::Unimplemented, answer);
+    }}
+}}
+"#
+    )
+}
+
+fn manifest_template(year: u16) -> String {
+    format!(
+        r#"[package]
+name = "aoc_{year}"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+aoc_lib = {{ path = "../aoc_lib" }}
+"#
+    )
+}
+
+fn lib_template(year: u16) -> String {
+    format!(
+        r#"use aoc_lib::registry::Entry;
+
+pub const YEAR: u16 = {year};
+
+pub const SOLUTIONS: &[Entry] = &[];
+
+pub const FILES_PREFIX_TEST: &str = "resources/test/";
+"#
+    )
+}
+
+fn workflow_template(year: u16) -> String {
+    format!(
+        r#"name: aoc_{year}
+
+on:
+  push:
+    branches: ["main"]
+  pull_request:
+    branches: ["main"]
+
+env:
+  CARGO_TERM_COLOR: always
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+
+    steps:
+      - uses: actions/checkout@v4
+      - name: Build
+        run: cargo build --verbose
+
+      - name: Run tests {year}
+        run: cargo test --verbose -p aoc_{year}
+"#
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::{day_template, insert_entry, insert_sorted, lib_template};
+
+    #[test]
+    fn test_insert_sorted() {
+        let content = "use aoc_lib::registry::Entry;\n\npub mod day01;\npub mod day03;\n\npub const YEAR: u16 = 2025;\n";
+        let inserted = insert_sorted(
+            content,
+            "pub mod day",
+            "pub mod day02;",
+            Some("pub const YEAR"),
+        );
+        assert_eq!(
+            "use aoc_lib::registry::Entry;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub const YEAR: u16 = 2025;\n",
+            inserted.unwrap()
+        );
+        let appended = insert_sorted(content, "pub mod day", "pub mod day04;", None).unwrap();
+        assert!(appended.contains("pub mod day03;\npub mod day04;\n\n"));
+        assert!(insert_sorted(content, "pub mod day", "pub mod day03;", None).is_err());
+    }
+
+    #[test]
+    fn test_insert_sorted_without_matches() {
+        let content = "use aoc_lib::registry::Entry;\n\npub const YEAR: u16 = 2026;\n";
+        assert_eq!(
+            "use aoc_lib::registry::Entry;\n\npub mod day01;\n\npub const YEAR: u16 = 2026;\n",
+            insert_sorted(
+                content,
+                "pub mod day",
+                "pub mod day01;",
+                Some("pub const YEAR")
+            )
+            .unwrap()
+        );
+        // without the anchor, the line goes to the end as its own paragraph
+        assert_eq!(
+            "use aoc_lib::registry::Entry;\n\npub const YEAR: u16 = 2026;\n\npub mod day01;\n",
+            insert_sorted(
+                content,
+                "pub mod day",
+                "pub mod day01;",
+                Some("pub const NOPE")
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_insert_entry() {
+        let empty = lib_template(2026);
+        let one = insert_entry(&empty, 3, "Third").unwrap();
+        assert!(one.contains(
+            "pub const SOLUTIONS: &[Entry] = &[\n    Entry::new(YEAR, 3, \"Third\", &day03::Day3),\n];\n"
+        ));
+        assert!(insert_entry(&one, 3, "Again").is_err());
+        // entries as laid out by rustfmt, on one line or spread over several
+        let formatted =
+            "pub const SOLUTIONS: &[Entry] = &[Entry::new(YEAR, 3, \"Third\", &day03::Day3)];\n";
+        let long = "pub const SOLUTIONS: &[Entry] = &[\n    Entry::new(\n        YEAR,\n        3,\n        \"Third\",\n        &day03::Day3,\n    ),\n];\n";
+        let expected = "pub const SOLUTIONS: &[Entry] = &[\n    Entry::new(YEAR, 1, \"First\", &day01::Day1),\n    Entry::new(YEAR, 3, \"Third\", &day03::Day3),\n];\n";
+        assert_eq!(expected, insert_entry(formatted, 1, "First").unwrap());
+        assert_eq!(expected, insert_entry(long, 1, "First").unwrap());
+        assert!(insert_entry("pub const YEAR: u16 = 2026;\n", 1, "First").is_err());
+    }
+
+    #[test]
+    fn test_insert_entry_with_comments() {
+        let content = "pub const SOLUTIONS: &[Entry] = &[\n    // the first days\n    Entry::new(YEAR, 23, \"A Long Walk\", &day23::Day23),\n    // Entry::new(YEAR, 24, \"Never Tell Me The Odds\", &day24::Day24),\n];\n";
+        assert_eq!(
+            "pub const SOLUTIONS: &[Entry] = &[\n    // the first days\n    Entry::new(YEAR, 23, \"A Long Walk\", &day23::Day23),\n    // Entry::new(YEAR, 24, \"Never Tell Me The Odds\", &day24::Day24),\n    Entry::new(YEAR, 25, \"Snowverload\", &day25::Day25),\n];\n",
+            insert_entry(content, 25, "Snowverload").unwrap()
+        );
+        assert_eq!(
+            "pub const SOLUTIONS: &[Entry] = &[\n    // the first days\n    Entry::new(YEAR, 22, \"Sand Slabs\", &day22::Day22),\n    Entry::new(YEAR, 23, \"A Long Walk\", &day23::Day23),\n    // Entry::new(YEAR, 24, \"Never Tell Me The Odds\", &day24::Day24),\n];\n",
+            insert_entry(content, 22, "Sand Slabs").unwrap()
+        );
+        assert!(insert_entry(content, 24, "Never Tell Me The Odds").is_err());
+        let trailing = "pub const SOLUTIONS: &[Entry] = &[\n    Entry::new(YEAR, 1, \"A\", &day01::Day1), // slow\n];\n";
+        assert!(insert_entry(trailing, 2, "B").is_err());
+    }
+
+    #[test]
+    fn test_day_template_width() {
+        assert!(day_template(25).lines().all(|line| line.len() <= 100));
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_lib::{
+    fetch::HttpBackend,
+    submit::{Submitter, Verdict},
+};
+
+use crate::{
+    answers::Part,
+    history::{self, HISTORY_FILE, History},
+};
+
+/// Submit `answer` unless the local history already tells it would be rejected or must wait.
+pub fn submit<B: HttpBackend>(
+    submitter: &Submitter<B>,
+    year: u16,
+    day: u16,
+    part: Part,
+    answer: &str,
+) -> anyhow::Result<()> {
+    let mut history = History::load(HISTORY_FILE)?;
+    let now = history::now();
+    if let Some(reason) = history.check(year, day, part, answer, now) {
+        anyhow::bail!(
+            "refusing to submit {answer} for {year} day {day:02} part {part:?}: {reason}"
+        );
+    }
+
+    let level = match part {
+        Part::A => 1,
+        Part::B => 2,
+    };
+    let verdict = submitter.submit(year, day, level, answer)?;
+    history.record(year, day, part, answer, &verdict, now);
+    history.save(HISTORY_FILE)?;
+
+    let prefix = format!("{year} day {day:02} part {part:?}: {answer}");
+    match verdict {
+        Verdict::Correct => println!("{prefix} is correct"),
+        Verdict::TooHigh => println!("{prefix} is too high"),
+        Verdict::TooLow => println!("{prefix} is too low"),
+        Verdict::Incorrect => println!("{prefix} is not the right answer"),
+        Verdict::Wait(seconds) => println!("{prefix} was not submitted, wait {seconds}s"),
+        Verdict::WrongLevel => {
+            println!("{prefix} was not judged, the part is already solved or still locked")
+        }
+        Verdict::Unknown(text) => println!("{prefix} got an unexpected response: {text}"),
+    }
+    Ok(())
+}
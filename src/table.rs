@@ -0,0 +1,24 @@
+/// Print `rows` as left aligned columns under `header`.
+pub fn print<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths = header.map(|h| h.chars().count());
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    print_row(header.iter(), &widths);
+    print_row(widths.map(|w| "-".repeat(w)).iter(), &widths);
+    for row in rows {
+        print_row(row.iter(), &widths);
+    }
+}
+
+fn print_row<S: AsRef<str>>(row: impl Iterator<Item = S>, widths: &[usize]) {
+    let line = row
+        .zip(widths)
+        .map(|(cell, &width)| format!("{:<width$}", cell.as_ref()))
+        .collect::<Vec<_>>()
+        .join(" | ");
+    println!("{}", line.trim_end());
+}
@@ -0,0 +1,81 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use aoc_lib::{answer::Answer, registry::Entry};
+
+use crate::{
+    answers::{self, Answers, Part},
+    inputs::Inputs,
+    runner,
+};
+
+/// Run `entries` and compare their answers with the answers file of their year.
+/// With `record`, answers of parts that have no recorded answer yet are written back.
+pub fn verify<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    inputs: &Inputs,
+    record: bool,
+) -> anyhow::Result<()> {
+    let reports = runner::run_all(entries, inputs);
+    let mut answers = reports
+        .iter()
+        .map(|r| r.year)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|year| Ok((year, Answers::load(&answers::answers_path(year))?)))
+        .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+
+    let (mut ok, mut failed, mut unrecorded, mut recorded) = (0, 0, 0, 0);
+    for report in &reports {
+        let known = answers.get_mut(&report.year).unwrap();
+        let prefix = format!("{} day {:02}", report.year, report.day);
+        let parts = [(Part::A, &report.part_a), (Part::B, &report.part_b)];
+        for (part, result) in parts {
+            let Some(result) = result else {
+                println!("{prefix}: {}", report.status());
+                break;
+            };
+            let name = format!("{prefix} part {part:?}");
+            let actual = match &result.answer {
+                Ok(Answer::Unimplemented) => {
+                    println!("{name}: unimplemented");
+                    continue;
+                }
+                Ok(actual) => actual,
+                Err(failure) => {
+                    println!("{name}: {failure}");
+                    failed += 1;
+                    continue;
+                }
+            };
+            match known.get(report.day, part) {
+                Some(expected) if expected == *actual => {
+                    ok += 1;
+                }
+                Some(expected) => {
+                    println!("{name}: mismatch, expected {expected} but got {actual}");
+                    failed += 1;
+                }
+                None if record && known.set(report.day, part, actual) => {
+                    println!("{name}: recorded {actual}");
+                    recorded += 1;
+                }
+                None => {
+                    println!("{name}: no recorded answer (got {actual})");
+                    unrecorded += 1;
+                }
+            }
+        }
+    }
+
+    if recorded > 0 {
+        for (year, known) in &answers {
+            known.save(&answers::answers_path(*year))?;
+        }
+    }
+
+    println!("{ok} ok, {failed} failed, {unrecorded} unrecorded, {recorded} recorded");
+    if failed > 0 {
+        anyhow::bail!("{failed} part(s) did not match the recorded answers");
+    }
+    Ok(())
+}